@@ -0,0 +1,37 @@
+use std::process::ExitCode;
+
+use clap::Args;
+
+use crate::{parse_file, Error, ParseError};
+
+/// The `--input` option shared by every day binary.
+#[derive(Args, Debug)]
+pub struct InputArgs {
+    /// Puzzle input, `-` reads it from stdin [default: the day's input.txt]
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+impl InputArgs {
+    pub fn filename<'a>(&'a self, default_input: &'a str) -> &'a str {
+        self.input.as_deref().unwrap_or(default_input)
+    }
+
+    pub fn load<T, F>(&self, default_input: &str, parse: F) -> Result<T, Error>
+    where
+        F: FnOnce(&str) -> Result<T, ParseError>,
+    {
+        parse_file(self.filename(default_input), parse)
+    }
+}
+
+/// Prints the error, if any, and turns the outcome into the exit code of the process.
+pub fn exit_code(result: Result<(), Error>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err.render());
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::{fmt, io, path::{Path, PathBuf}};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub source_line: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error about `text`, found at the 1-based `line` and `column` of the input.
+    pub fn new(line: usize, column: usize, source_line: &str, text: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            file: None,
+            line,
+            column,
+            text: String::from(text),
+            source_line: String::from(source_line),
+            message: message.into(),
+        }
+    }
+
+    /// Error about `fragment`, a slice of `source_line`; the column is derived from its position.
+    pub fn in_line(line: usize, source_line: &str, fragment: &str, message: impl Into<String>) -> ParseError {
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(source_line.as_ptr() as usize);
+        let column = match source_line.get(..offset) {
+            Some(prefix) if offset + fragment.len() <= source_line.len() => prefix.chars().count() + 1,
+            _ => 1,
+        };
+
+        ParseError::new(line, column, source_line, fragment, message)
+    }
+
+    /// Error about a whole line of the input.
+    pub fn whole_line(line: usize, source_line: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(line, 1, source_line, source_line, message)
+    }
+
+    pub fn with_file(mut self, file: &Path) -> ParseError {
+        self.file = Some(file.to_path_buf());
+        self
+    }
+
+    /// Renders the error the way rustc does, with the offending text underlined.
+    pub fn render(&self) -> String {
+        self.render_as("error")
+    }
+
+    /// Same as `render`, for an error that was tolerated.
+    pub fn render_warning(&self) -> String {
+        self.render_as("warning")
+    }
+
+    fn render_as(&self, level: &str) -> String {
+        let location = match &self.file {
+            Some(file) => format!("{}:{}:{}", file.display(), self.line, self.column),
+            None => format!("<input>:{}:{}", self.line, self.column),
+        };
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let underline = "^".repeat(self.text.chars().count().max(1));
+        let padding = " ".repeat(self.column.saturating_sub(1));
+
+        format!(
+            "{}: {}\n{}--> {}\n{} |\n{} | {}\n{} | {}{}",
+            level, self.message, gutter, location, gutter, line_number, self.source_line, gutter, padding, underline
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(f, "{}:{}: {} (found `{}`)", self.line, self.column, self.message, self.text)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug)]
+pub enum Error {
+    Io { path: PathBuf, source: io::Error },
+    Parse(ParseError),
+}
+
+impl Error {
+    /// Renders parse errors with their source line, other errors on a single line.
+    pub fn render(&self) -> String {
+        match self {
+            Error::Parse(err) => err.render(),
+            _ => format!("error: {}", self),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "cannot read {}: {}", path.display(), source),
+            Error::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Error {
+        Error::Parse(err)
+    }
+}
@@ -0,0 +1,85 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{lines, ParseError};
+
+/// Rectangular grid stored row by row; `grid[row][col]` indexes a single cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid { cells: vec![fill; width * height], width, height }
+    }
+
+    pub fn with_same_size<U: Clone>(&self, fill: U) -> Grid<U> {
+        Grid::new(self.width, self.height, fill)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+}
+
+impl Grid<u32> {
+    pub fn parse_digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (line_number, row) in lines(input) {
+            let row_start = cells.len();
+            for (idx, ch) in row.char_indices() {
+                match ch.to_digit(10) {
+                    Some(digit) => cells.push(digit),
+                    None => {
+                        let cell = &row[idx..idx + ch.len_utf8()];
+                        return Err(ParseError::in_line(line_number, row, cell, "expected a digit"));
+                    }
+                }
+            }
+
+            let row_width = cells.len() - row_start;
+            if height == 0 {
+                width = row_width;
+            } else if row_width != width {
+                let message = format!("expected {} cells, found {}", width, row_width);
+                return Err(ParseError::whole_line(line_number, row, message));
+            }
+            height += 1;
+        }
+
+        if height == 0 {
+            return Err(ParseError::new(1, 1, "", "", "the grid is empty"));
+        }
+
+        Ok(Grid { cells, width, height })
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = [T];
+
+    fn index(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+}
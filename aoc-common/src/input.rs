@@ -0,0 +1,47 @@
+use std::{fs, io::{self, Read}, path::Path};
+
+use crate::{Error, ParseError};
+
+/// Name of the input that `read_input` takes from stdin.
+pub const STDIN: &str = "-";
+
+/// Reads the whole input from `filename`, or from stdin when it is `-`.
+pub fn read_input<P: AsRef<Path>>(filename: P) -> Result<String, Error> {
+    let path = filename.as_ref();
+    let content = if path == Path::new(STDIN) {
+        let mut content = String::new();
+        io::stdin().read_to_string(&mut content).map(|_| content)
+    } else {
+        fs::read_to_string(path)
+    };
+
+    content.map_err(|source| Error::Io { path: path.to_path_buf(), source })
+}
+
+/// How an input is named in diagnostics.
+pub fn input_name(filename: &Path) -> &Path {
+    if filename == Path::new(STDIN) {
+        Path::new("<stdin>")
+    } else {
+        filename
+    }
+}
+
+/// Reads `filename` and hands its content to `parse`, tagging parse errors with the file name.
+pub fn parse_file<P, T, F>(filename: P, parse: F) -> Result<T, Error>
+where
+    P: AsRef<Path>,
+    F: FnOnce(&str) -> Result<T, ParseError>,
+{
+    let input = read_input(&filename)?;
+
+    parse(&input).map_err(|err| Error::Parse(err.with_file(input_name(filename.as_ref()))))
+}
+
+/// Non-empty lines of the input, paired with their 1-based line number.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line))
+        .filter(|(_, line)| !line.is_empty())
+}
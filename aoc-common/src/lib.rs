@@ -0,0 +1,11 @@
+mod cli;
+mod error;
+mod grid;
+mod input;
+mod solution;
+
+pub use cli::{exit_code, InputArgs};
+pub use error::{Error, ParseError};
+pub use grid::Grid;
+pub use input::{input_name, lines, parse_file, read_input, STDIN};
+pub use solution::{Answer, Solution};
@@ -0,0 +1,60 @@
+use std::fmt;
+
+use crate::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Answer {
+        Answer::Number(value.into())
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Answer {
+        Answer::Number(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Answer {
+        Answer::Number(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Answer {
+        i64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Number)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Answer {
+        Answer::Text(value)
+    }
+}
+
+/// A day of the calendar, as seen by the `aoc` runner.
+pub trait Solution {
+    fn day(&self) -> u8;
+
+    /// The puzzle input checked in next to the day's crate.
+    fn default_input(&self) -> &'static str;
+
+    fn step_1(&self, input: &str) -> Result<Answer, ParseError>;
+
+    fn step_2(&self, input: &str) -> Result<Answer, ParseError>;
+}
@@ -0,0 +1,49 @@
+//! Parse and solve times of every day on its checked-in `input.txt`.
+//!
+//! Save a baseline with `cargo bench -p aoc -- --save-baseline main`, benchmark the
+//! change with `cargo bench -p aoc`, then compare the two with
+//! `cargo run -p aoc -- bench-report --baseline main`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+macro_rules! input {
+    ($day:literal) => {
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../", $day, "/input.txt"))
+    };
+}
+
+/// Benchmarks `parse`, `part1` and `part2` of a day whose crate exposes
+/// `parse_input`, `step_1` and `step_2`.
+macro_rules! bench_day {
+    ($c:expr, $name:literal, $parse:path, $step_1:path, $step_2:path) => {{
+        let raw = input!($name);
+        let parsed = $parse(raw).expect("the checked-in input should parse");
+
+        let mut group = $c.benchmark_group($name);
+        group.bench_function("parse", |b| b.iter(|| $parse(black_box(raw))));
+        group.bench_function("part1", |b| b.iter(|| $step_1(black_box(&parsed))));
+        group.bench_function("part2", |b| b.iter(|| $step_2(black_box(&parsed))));
+        group.finish();
+    }};
+}
+
+fn days(c: &mut Criterion) {
+    bench_day!(c, "day01", day01::parse_input, day01::step_1, day01::step_2);
+    bench_day!(c, "day02", day02::parse_input, day02::step_1, day02::step_2);
+    bench_day!(c, "day03", day03::parse_input, day03::step_1, day03::step_2);
+    bench_day!(c, "day04", day04::parse_input, day04::step_1, day04::step_2);
+    bench_day!(c, "day05", day05::parse_input, day05::step_1, day05::step_2);
+    bench_day!(c, "day07", day07::history_parser::parse_filesystem, day07::step_1, day07::step_2);
+    bench_day!(c, "day08", day08::parse_input, day08::tree_visibility::step_1, day08::tree_scenic_score::step_2);
+    bench_day!(c, "day09", day09::parse_input, day09::step_1, day09::step_2);
+
+    // Day 6 works on the raw datastream, there is nothing to parse.
+    let raw = input!("day06");
+    let mut group = c.benchmark_group("day06");
+    group.bench_function("part1", |b| b.iter(|| day06::step_1(black_box(raw))));
+    group.bench_function("part2", |b| b.iter(|| day06::step_2(black_box(raw))));
+    group.finish();
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
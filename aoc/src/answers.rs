@@ -0,0 +1,77 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use aoc_common::Answer;
+use serde::{Deserialize, Serialize};
+
+/// Answers known for one puzzle input.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct KnownAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl KnownAnswers {
+    pub fn get(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part1.as_ref(),
+            _ => self.part2.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &Answer) {
+        let answer = Some(answer.to_string());
+        match part {
+            1 => self.part1 = answer,
+            _ => self.part2 = answer,
+        }
+    }
+}
+
+/// Content of a `dayNN/answers.toml` file, keyed by the hash of the input.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AnswersRegistry {
+    #[serde(default)]
+    pub inputs: BTreeMap<String, KnownAnswers>,
+}
+
+impl AnswersRegistry {
+    pub fn load(path: &Path) -> Result<AnswersRegistry, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(AnswersRegistry::default()),
+            Err(err) => return Err(format!("cannot read {}: {}", path.display(), err)),
+        };
+
+        toml::from_str(&content).map_err(|err| format!("cannot parse {}: {}", path.display(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = toml::to_string(self).map_err(|err| err.to_string())?;
+
+        fs::write(path, content).map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    }
+}
+
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+pub fn verify(known: Option<&KnownAnswers>, part: u8, answer: &Answer) -> Verdict {
+    match known.and_then(|known| known.get(part)) {
+        Some(expected) if *expected == answer.to_string() => Verdict::Pass,
+        Some(expected) => Verdict::Fail { expected: expected.clone() },
+        None => Verdict::Unknown,
+    }
+}
+
+/// 64-bit FNV-1a hash of the input, stable across toolchains and platforms.
+pub fn input_hash(input: &[u8]) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let hash = input.iter().fold(OFFSET_BASIS, |hash, &byte| (hash ^ byte as u64).wrapping_mul(PRIME));
+
+    format!("{:016x}", hash)
+}
@@ -0,0 +1,91 @@
+use std::{fs, path::Path};
+
+use serde_json::Value;
+
+/// Mean time of one benchmark in the current run and in the saved baseline, in nanoseconds.
+pub struct BenchComparison {
+    pub id: String,
+    pub baseline: Option<f64>,
+    pub current: f64,
+}
+
+fn read_mean(estimates_file: &Path) -> Option<f64> {
+    let content = fs::read_to_string(estimates_file).ok()?;
+    let estimates: Value = serde_json::from_str(&content).ok()?;
+
+    estimates["mean"]["point_estimate"].as_f64()
+}
+
+fn sorted_subdirectories(dir: &Path) -> Result<Vec<String>, String> {
+    let entries = fs::read_dir(dir).map_err(|err| format!("cannot read {}: {}", dir.display(), err))?;
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+
+    Ok(names)
+}
+
+/// Reads the `<group>/<function>/{new,<baseline>}/estimates.json` files criterion leaves behind.
+pub fn collect(criterion_dir: &Path, baseline: &str) -> Result<Vec<BenchComparison>, String> {
+    let mut comparisons = Vec::new();
+
+    for group in sorted_subdirectories(criterion_dir)? {
+        if group == "report" {
+            continue;
+        }
+
+        let group_dir = criterion_dir.join(&group);
+        for function in sorted_subdirectories(&group_dir)? {
+            let bench_dir = group_dir.join(&function);
+            let current = match read_mean(&bench_dir.join("new").join("estimates.json")) {
+                Some(current) => current,
+                None => continue,
+            };
+
+            comparisons.push(BenchComparison {
+                id: format!("{}/{}", group, function),
+                baseline: read_mean(&bench_dir.join(baseline).join("estimates.json")),
+                current,
+            });
+        }
+    }
+
+    Ok(comparisons)
+}
+
+fn format_duration(nanoseconds: f64) -> String {
+    if nanoseconds >= 1e9 {
+        format!("{:.2} s", nanoseconds / 1e9)
+    } else if nanoseconds >= 1e6 {
+        format!("{:.2} ms", nanoseconds / 1e6)
+    } else if nanoseconds >= 1e3 {
+        format!("{:.2} µs", nanoseconds / 1e3)
+    } else {
+        format!("{:.2} ns", nanoseconds)
+    }
+}
+
+pub fn render_table(comparisons: &[BenchComparison], baseline: &str) -> String {
+    let mut table = format!("{:<20} {:>12} {:>12} {:>9}\n", "benchmark", baseline, "current", "change");
+
+    for comparison in comparisons {
+        let (baseline, change) = match comparison.baseline {
+            Some(baseline) => {
+                let change = (comparison.current - baseline) / baseline * 100.0;
+                (format_duration(baseline), format!("{:+.1}%", change))
+            }
+            None => (String::from("-"), String::from("-")),
+        };
+
+        table.push_str(&format!(
+            "{:<20} {:>12} {:>12} {:>9}\n",
+            comparison.id, baseline, format_duration(comparison.current), change
+        ));
+    }
+
+    table
+}
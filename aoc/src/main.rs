@@ -0,0 +1,264 @@
+mod answers;
+mod bench_report;
+
+use std::{fs, path::{Path, PathBuf}, process::ExitCode};
+
+use aoc_common::{input_name, Answer, Error, Solution};
+use answers::{AnswersRegistry, Verdict};
+use clap::{Args, Parser, Subcommand};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day with --all, and print the answers
+    Run(Selection),
+    /// Compare the answers with the ones recorded in dayNN/answers.toml
+    Verify(VerifyArgs),
+    /// Compare the last `cargo bench` run with a saved criterion baseline
+    BenchReport(BenchReportArgs),
+    /// Generate a random puzzle input for a day
+    Generate(GenerateArgs),
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Day to run (1-9)
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Part to run; both parts are run when omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input, `-` reads it from stdin [default: the day's input.txt]
+    #[arg(long, conflicts_with = "all")]
+    input: Option<String>,
+
+    /// Run every day in sequence
+    #[arg(long)]
+    all: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Record the answers of the parts not in the registry yet
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct BenchReportArgs {
+    /// Baseline saved with `cargo bench -- --save-baseline <name>`
+    #[arg(long, default_value = "base")]
+    baseline: String,
+
+    /// Directory where criterion stores its results
+    #[arg(long, default_value = "target/criterion")]
+    criterion_dir: PathBuf,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    #[command(subcommand)]
+    day: GenerateDay,
+
+    /// Seed of the generator; a random one is picked, and printed on stderr, when omitted
+    #[arg(long, global = true)]
+    seed: Option<u64>,
+
+    /// Write the input to this file instead of stdout
+    #[arg(long, global = true)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum GenerateDay {
+    Day01(day01::generator::Params),
+    Day02(day02::generator::Params),
+    Day03(day03::generator::Params),
+    Day04(day04::generator::Params),
+    Day05(day05::generator::Params),
+    Day06(day06::generator::Params),
+    Day07(day07::generator::Params),
+    Day08(day08::generator::Params),
+    Day09(day09::generator::Params),
+}
+
+fn solutions() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(day01::Day01),
+        Box::new(day02::Day02),
+        Box::new(day03::Day03),
+        Box::new(day04::Day04),
+        Box::new(day05::Day05),
+        Box::new(day06::Day06),
+        Box::new(day07::Day07),
+        Box::new(day08::Day08),
+        Box::new(day09::Day09),
+    ]
+}
+
+fn answers_file(solution: &dyn Solution) -> PathBuf {
+    Path::new(solution.default_input()).with_file_name("answers.toml")
+}
+
+/// The input selected on the command line, or the day's own one.
+fn load_input<'a>(solution: &'a dyn Solution, selection: &'a Selection) -> Result<(&'a str, String), String> {
+    let filename = selection.input.as_deref().unwrap_or(solution.default_input());
+    let input = aoc_common::read_input(filename).map_err(|err| err.render())?;
+
+    Ok((filename, input))
+}
+
+fn solve(solution: &dyn Solution, part: u8, filename: &str, input: &str) -> Result<Answer, String> {
+    let answer = match part {
+        1 => solution.step_1(input),
+        _ => solution.step_2(input),
+    };
+
+    answer.map_err(|err| Error::from(err.with_file(input_name(Path::new(filename)))).render())
+}
+
+fn select<'a>(solutions: &'a [Box<dyn Solution>], selection: &Selection) -> Result<Vec<&'a dyn Solution>, String> {
+    match selection.day {
+        Some(day) => {
+            let solution = solutions.iter()
+                .find(|solution| solution.day() == day)
+                .ok_or_else(|| format!("error: there is no solution for day {}", day))?;
+            Ok(vec![solution.as_ref()])
+        }
+        None => Ok(solutions.iter().map(|solution| solution.as_ref()).collect()),
+    }
+}
+
+fn parts(selection: &Selection) -> Vec<u8> {
+    match selection.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn run(selection: &Selection) -> Result<(), String> {
+    let solutions = solutions();
+
+    for solution in select(&solutions, selection)? {
+        let (filename, input) = load_input(solution, selection)?;
+        for part in parts(selection) {
+            let answer = solve(solution, part, filename, &input)?;
+            println!("Day {:02} part {}: {}", solution.day(), part, answer);
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let solutions = solutions();
+    let mut failures = 0;
+
+    for solution in select(&solutions, &args.selection)? {
+        let (filename, input) = load_input(solution, &args.selection)?;
+        let hash = answers::input_hash(input.as_bytes());
+
+        let registry_path = answers_file(solution);
+        let mut registry = AnswersRegistry::load(&registry_path)?;
+        let mut recorded = false;
+
+        for part in parts(&args.selection) {
+            let answer = solve(solution, part, filename, &input)?;
+            let outcome = match answers::verify(registry.inputs.get(&hash), part, &answer) {
+                Verdict::Pass => String::from("pass"),
+                Verdict::Fail { expected } => {
+                    failures += 1;
+                    format!("FAIL (expected {})", expected)
+                }
+                Verdict::Unknown if args.record => {
+                    registry.inputs.entry(hash.clone()).or_default().set(part, &answer);
+                    recorded = true;
+                    String::from("recorded")
+                }
+                Verdict::Unknown => String::from("unknown"),
+            };
+            println!("Day {:02} part {}: {} ... {}", solution.day(), part, answer, outcome);
+        }
+
+        if recorded {
+            registry.save(&registry_path)?;
+        }
+    }
+
+    match failures {
+        0 => Ok(()),
+        _ => Err(format!("error: {} answer(s) do not match the registry", failures)),
+    }
+}
+
+fn bench_report(args: &BenchReportArgs) -> Result<(), String> {
+    let comparisons = bench_report::collect(&args.criterion_dir, &args.baseline).map_err(|err| format!("error: {}", err))?;
+    if comparisons.is_empty() {
+        return Err(format!("error: no benchmark results in {}, run `cargo bench` first", args.criterion_dir.display()));
+    }
+
+    print!("{}", bench_report::render_table(&comparisons, &args.baseline));
+
+    Ok(())
+}
+
+fn generate(args: &GenerateArgs) -> Result<(), String> {
+    let seed = args.seed.unwrap_or_else(|| {
+        let seed = rand::random();
+        eprintln!("seed: {}", seed);
+        seed
+    });
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let input = match &args.day {
+        GenerateDay::Day01(params) => day01::generator::generate(&mut rng, params),
+        GenerateDay::Day02(params) => day02::generator::generate(&mut rng, params),
+        GenerateDay::Day03(params) => day03::generator::generate(&mut rng, params),
+        GenerateDay::Day04(params) => day04::generator::generate(&mut rng, params),
+        GenerateDay::Day05(params) => day05::generator::generate(&mut rng, params),
+        GenerateDay::Day06(params) => day06::generator::generate(&mut rng, params),
+        GenerateDay::Day07(params) => day07::generator::generate(&mut rng, params),
+        GenerateDay::Day08(params) => day08::generator::generate(&mut rng, params),
+        GenerateDay::Day09(params) => day09::generator::generate(&mut rng, params),
+    };
+
+    match &args.output {
+        Some(path) => fs::write(path, input).map_err(|err| format!("error: cannot write {}: {}", path.display(), err)),
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match &cli.command {
+        Command::Run(selection) => run(selection),
+        Command::Verify(args) => verify(args),
+        Command::BenchReport(args) => bench_report(args),
+        Command::Generate(args) => generate(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! Differential testing: the optimised solutions must give the same answers as the naïve
+//! reference ones on generated inputs.
+//!
+//! The first input the two disagree on is saved under the cargo target directory, and the
+//! failure message tells the seed to regenerate it with `aoc generate`.
+
+use std::{fmt::Debug, fs, path::PathBuf};
+
+use aoc_common::ParseError;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+const SEEDS: u64 = 50;
+
+fn differential<T, G, S, R>(day: &str, generate: G, solve: S, reference: R)
+where
+    T: PartialEq + Debug,
+    G: Fn(&mut ChaCha8Rng) -> String,
+    S: Fn(&str) -> Result<T, ParseError>,
+    R: Fn(&str) -> Result<T, ParseError>,
+{
+    for seed in 0..SEEDS {
+        let input = generate(&mut ChaCha8Rng::seed_from_u64(seed));
+        let answers = solve(&input).unwrap();
+        let expected = reference(&input).unwrap();
+
+        if answers != expected {
+            let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(format!("{}-seed-{}.txt", day, seed));
+            fs::write(&path, &input).unwrap();
+            panic!(
+                "{} with seed {}: the solution answered {:?}, the reference {:?}\ninput saved in {}",
+                day, seed, answers, expected, path.display()
+            );
+        }
+    }
+}
+
+macro_rules! differential_test {
+    ($name:ident, $day:ident, $params:expr) => {
+        #[test]
+        fn $name() {
+            let params = $params;
+            differential(
+                stringify!($day),
+                |rng| $day::generator::generate(rng, &params),
+                $day::solve_str,
+                $day::reference::solve_str,
+            );
+        }
+    };
+}
+
+differential_test!(day01_matches_the_reference, day01, day01::generator::Params { elves: 50, max_meals: 10, max_calories: 70000 });
+differential_test!(day02_matches_the_reference, day02, day02::generator::Params { rounds: 200 });
+differential_test!(day03_matches_the_reference, day03, day03::generator::Params { groups: 20, max_items: 16 });
+differential_test!(day04_matches_the_reference, day04, day04::generator::Params { pairs: 200, sections: 30 });
+differential_test!(day05_matches_the_reference, day05, day05::generator::Params { stacks: 5, height: 6, moves: 100 });
+differential_test!(day06_matches_the_reference, day06, day06::generator::Params { length: 300, alphabet: 8 });
+differential_test!(day07_matches_the_reference, day07, day07::generator::Params { depth: 3, max_subdirectories: 3, max_files: 4 });
+differential_test!(day08_matches_the_reference, day08, day08::generator::Params { width: 12, height: 9 });
+differential_test!(day09_matches_the_reference, day09, day09::generator::Params { moves: 100, max_step: 8 });
+
+#[test]
+fn day02_classic_rule_set_matches_the_solution() {
+    let params = day02::generator::Params { rounds: 200 };
+    let rules = day02::rules::RuleSet::classic();
+
+    differential("day02-classic-rules", |rng| day02::generator::generate(rng, &params), day02::solve_str, |input| rules.solve_str(input));
+}
@@ -0,0 +1,32 @@
+//! Generated inputs must be reproducible from their seed and accepted by the solutions.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+macro_rules! generator_test {
+    ($name:ident, $day:ident, $params:expr) => {
+        #[test]
+        fn $name() {
+            let params = $params;
+            for seed in 0..20 {
+                let input = $day::generator::generate(&mut ChaCha8Rng::seed_from_u64(seed), &params);
+                let again = $day::generator::generate(&mut ChaCha8Rng::seed_from_u64(seed), &params);
+
+                assert_eq!(input, again, "seed {} is not reproducible", seed);
+                if let Err(err) = $day::solve_str(&input) {
+                    panic!("seed {} generated an invalid input:\n{}\n{}", seed, err.render(), input);
+                }
+            }
+        }
+    };
+}
+
+generator_test!(day01_generator, day01, day01::generator::Params { elves: 50, max_meals: 10, max_calories: 70000 });
+generator_test!(day02_generator, day02, day02::generator::Params { rounds: 100 });
+generator_test!(day03_generator, day03, day03::generator::Params { groups: 20, max_items: 16 });
+generator_test!(day04_generator, day04, day04::generator::Params { pairs: 100, sections: 99 });
+generator_test!(day05_generator, day05, day05::generator::Params { stacks: 9, height: 8, moves: 100 });
+generator_test!(day06_generator, day06, day06::generator::Params { length: 500, alphabet: 10 });
+generator_test!(day07_generator, day07, day07::generator::Params { depth: 4, max_subdirectories: 3, max_files: 4 });
+generator_test!(day08_generator, day08, day08::generator::Params { width: 30, height: 20 });
+generator_test!(day09_generator, day09, day09::generator::Params { moves: 200, max_step: 20 });
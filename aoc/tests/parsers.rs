@@ -0,0 +1,124 @@
+//! Feeds every parser random and mutated inputs: a parser may reject them, but must never panic,
+//! and neither may the solver on the inputs the parser accepts.
+
+use aoc_common::ParseError;
+use proptest::prelude::*;
+
+macro_rules! example {
+    ($day:literal) => {
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../", $day, "/example.txt"))
+    };
+}
+
+#[derive(Debug, Clone)]
+enum Mutation {
+    Insert(usize, char),
+    Remove(usize),
+    Replace(usize, char),
+    Truncate(usize),
+}
+
+fn interesting_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        any::<char>(),
+        prop::sample::select(vec!['\n', '\r', ' ', '-', ',', '$', '[', ']', '0', '1', '9', 'a', 'Z']),
+    ]
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    prop_oneof![
+        (any::<usize>(), interesting_char()).prop_map(|(idx, ch)| Mutation::Insert(idx, ch)),
+        any::<usize>().prop_map(Mutation::Remove),
+        (any::<usize>(), interesting_char()).prop_map(|(idx, ch)| Mutation::Replace(idx, ch)),
+        any::<usize>().prop_map(Mutation::Truncate),
+    ]
+}
+
+fn apply(seed: &str, mutations: &[Mutation]) -> String {
+    let mut chars: Vec<char> = seed.chars().collect();
+
+    for mutation in mutations {
+        let len = chars.len().max(1);
+        match *mutation {
+            Mutation::Insert(idx, ch) => chars.insert(idx % (chars.len() + 1), ch),
+            Mutation::Remove(idx) if !chars.is_empty() => {
+                chars.remove(idx % len);
+            }
+            Mutation::Replace(idx, ch) if !chars.is_empty() => chars[idx % len] = ch,
+            Mutation::Truncate(idx) => chars.truncate(idx % len),
+            _ => {}
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+/// A worked example with a handful of random edits.
+fn mutated(seed: &'static str) -> impl Strategy<Value = String> {
+    prop::collection::vec(mutation(), 1..8).prop_map(move |mutations| apply(seed, &mutations))
+}
+
+/// Errors must point somewhere inside the input.
+fn check<T>(input: &str, result: Result<T, ParseError>) -> Result<(), TestCaseError> {
+    if let Err(err) = result {
+        prop_assert!(err.line >= 1 && err.line <= input.split('\n').count() + 1, "line {} out of the input", err.line);
+        prop_assert!(err.column >= 1);
+    }
+
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn day01_parser(input in prop_oneof![mutated(example!("day01")), "([0-9]{1,11}\n{1,2}){0,6}", "\\PC{0,100}"]) {
+        check(&input, day01::parse_input(&input))?;
+        check(&input, day01::solve_str(&input))?;
+    }
+
+    #[test]
+    fn day02_parser(input in prop_oneof![mutated(example!("day02")), "([A-C] [X-Z]\n){0,10}", "\\PC{0,100}"]) {
+        check(&input, day02::parse_input(&input))?;
+        check(&input, day02::solve_str(&input))?;
+    }
+
+    #[test]
+    fn day03_parser(input in prop_oneof![mutated(example!("day03")), "\\PC{0,100}"]) {
+        check(&input, day03::parse_input(&input))?;
+        check(&input, day03::solve_str(&input))?;
+    }
+
+    #[test]
+    fn day04_parser(input in prop_oneof![mutated(example!("day04")), "([0-9]{1,3}-[0-9]{1,3},[0-9]{1,3}-[0-9]{1,3}\n){0,5}", "[0-9,\\-\n]{0,60}"]) {
+        check(&input, day04::parse_input(&input))?;
+        check(&input, day04::solve_str(&input))?;
+    }
+
+    #[test]
+    fn day05_parser(input in prop_oneof![mutated(example!("day05")), "([\\[\\] A-Z]{0,12}\n){0,3} [0-9]{1,20} \n\n(move [0-9]{1,3} from [0-9] to [0-9]\n){0,3}", "[\\[\\] A-Z0-9\n]{0,100}", "\\PC{0,100}"]) {
+        check(&input, day05::parse_input(&input))?;
+        check(&input, day05::solve_str(&input))?;
+    }
+
+    #[test]
+    fn day06_parser(input in prop_oneof![mutated(example!("day06")), "[a-e]{0,30}\n?", "\\PC{0,100}"]) {
+        check(&input, day06::solve_str(&input))?;
+    }
+
+    #[test]
+    fn day07_parser(input in prop_oneof![mutated(example!("day07")), "(\\$ (cd [a-z/.]{1,2}|ls)\n([0-9]{1,3} [a-z]\n|dir [a-z]\n|dir \n|dir /\n){0,3}){0,6}", "\\PC{0,100}"]) {
+        check(&input, day07::history_parser::parse_filesystem(&input))?;
+        check(&input, day07::solve_str(&input))?;
+    }
+
+    #[test]
+    fn day08_parser(input in prop_oneof![mutated(example!("day08")), "[0-9\n]{0,60}", "\\PC{0,100}"]) {
+        check(&input, day08::parse_input(&input))?;
+        check(&input, day08::solve_str(&input))?;
+    }
+
+    #[test]
+    fn day09_parser(input in prop_oneof![mutated(example!("day09")), "([LRUD] [0-9]{1,3}\n){0,10}", "\\PC{0,100}"]) {
+        check(&input, day09::parse_input(&input))?;
+        check(&input, day09::solve_str(&input))?;
+    }
+}
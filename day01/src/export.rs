@@ -0,0 +1,116 @@
+use std::fmt::Write;
+
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+/// The calories of each elf, numbered from 1 in the order of the records.
+pub fn export(calories_per_elves: &[i32], format: Format) -> String {
+    let mut output = String::new();
+
+    match format {
+        Format::Csv => {
+            output.push_str("elf,calories\n");
+            for (idx, calories) in calories_per_elves.iter().enumerate() {
+                writeln!(output, "{},{}", idx + 1, calories).unwrap();
+            }
+        }
+        Format::Json => {
+            let elves: Vec<String> = calories_per_elves.iter()
+                .enumerate()
+                .map(|(idx, calories)| format!("  {{\"elf\": {}, \"calories\": {}}}", idx + 1, calories))
+                .collect();
+            writeln!(output, "[\n{}\n]", elves.join(",\n")).unwrap();
+        }
+    }
+
+    output
+}
+
+/// Width of the longest bar of the histogram.
+const BAR_WIDTH: usize = 40;
+
+/// Splits the range of the calories in `buckets` ranges of sizes differing by at most one, fewer
+/// when the range holds fewer values, and counts the elves in each. The last range ends at the maximum.
+pub fn buckets(calories_per_elves: &[i32], buckets: usize) -> Vec<(i64, i64, usize)> {
+    let (Some(&min), Some(&max)) = (calories_per_elves.iter().min(), calories_per_elves.iter().max()) else {
+        return Vec::new();
+    };
+
+    let (min, max) = (min as i64, max as i64);
+    let width = max - min + 1;
+    let num_buckets = (buckets.max(1) as i64).min(width);
+    let first = |idx: i64| min + idx * width / num_buckets;
+
+    let mut counts = vec![0; num_buckets as usize];
+    for &calories in calories_per_elves {
+        counts[(((calories as i64 - min + 1) * num_buckets - 1) / width) as usize] += 1;
+    }
+
+    counts.into_iter()
+        .enumerate()
+        .map(|(idx, count)| (first(idx as i64), first(idx as i64 + 1) - 1, count))
+        .collect()
+}
+
+pub fn histogram(calories_per_elves: &[i32], num_buckets: usize) -> String {
+    let buckets = buckets(calories_per_elves, num_buckets);
+    let max_count = buckets.iter().map(|&(_, _, count)| count).max().unwrap_or(0);
+    let label_width = buckets.iter().map(|&(first, last, _)| first.to_string().len().max(last.to_string().len())).max().unwrap_or(0);
+
+    let mut output = String::new();
+    for (first, last, count) in buckets {
+        let bar = "#".repeat((count * BAR_WIDTH).div_ceil(max_count));
+        writeln!(output, "{:>width$} - {:>width$} | {:<bar_width$} {}", first, last, bar, count, width = label_width, bar_width = BAR_WIDTH).unwrap();
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CALORIES: [i32; 5] = [6000, 4000, 11000, 24000, 10000];
+
+    #[test]
+    fn exports_the_calories_as_csv() {
+        assert_eq!(export(&CALORIES[..2], Format::Csv), "elf,calories\n1,6000\n2,4000\n");
+    }
+
+    #[test]
+    fn exports_the_calories_as_json() {
+        assert_eq!(export(&CALORIES[..2], Format::Json), "[\n  {\"elf\": 1, \"calories\": 6000},\n  {\"elf\": 2, \"calories\": 4000}\n]\n");
+    }
+
+    #[test]
+    fn counts_the_elves_in_each_bucket() {
+        assert_eq!(buckets(&CALORIES, 4), vec![(4000, 8999, 2), (9000, 13999, 2), (14000, 18999, 0), (19000, 24000, 1)]);
+        assert_eq!(buckets(&[5, 5], 3), vec![(5, 5, 2)]);
+        assert_eq!(buckets(&[], 3), vec![]);
+    }
+
+    #[test]
+    fn makes_as_many_buckets_as_asked_up_to_the_maximum() {
+        let calories: Vec<i32> = (1..=10).collect();
+
+        assert_eq!(buckets(&calories, 7), vec![(1, 1, 1), (2, 2, 1), (3, 4, 2), (5, 5, 1), (6, 7, 2), (8, 8, 1), (9, 10, 2)]);
+        assert_eq!(buckets(&calories, 3).iter().map(|&(first, last, _)| (first, last)).collect::<Vec<_>>(), vec![(1, 3), (4, 6), (7, 10)]);
+        assert_eq!(buckets(&calories, 20).len(), 10);
+        assert_eq!(buckets(&[i32::MIN, i32::MAX], 3).last(), Some(&(715827882, i32::MAX as i64, 1)));
+    }
+
+    #[test]
+    fn draws_a_bar_for_each_bucket() {
+        let histogram = histogram(&CALORIES, 2);
+
+        assert_eq!(histogram.lines().collect::<Vec<_>>(), vec![
+            " 4000 - 13999 | ######################################## 4",
+            "14000 - 24000 | ##########                               1",
+        ]);
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt::Write;
+
+use clap::Args;
+use rand::Rng;
+
+#[derive(Args, Debug, Clone)]
+pub struct Params {
+    /// Number of elves
+    #[arg(long, default_value_t = 250)]
+    pub elves: usize,
+
+    /// Most meals carried by a single elf
+    #[arg(long, default_value_t = 15)]
+    pub max_meals: usize,
+
+    /// Most calories in a single meal
+    #[arg(long, default_value_t = 70000)]
+    pub max_calories: u32,
+}
+
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, params: &Params) -> String {
+    let mut input = String::new();
+
+    for elf in 0..params.elves {
+        if elf > 0 {
+            input.push('\n');
+        }
+        for _ in 0..rng.gen_range(1..=params.max_meals.max(1)) {
+            writeln!(input, "{}", rng.gen_range(1..=params.max_calories.max(1))).unwrap();
+        }
+    }
+
+    input
+}
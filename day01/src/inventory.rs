@@ -0,0 +1,68 @@
+use aoc_common::{Error, ParseError};
+
+use crate::CaloriesReader;
+
+/// An elf and the calories of each meal it carries, in the order of the records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// Position of the elf in the records, starting from 1 as in the puzzle text.
+    pub index: usize,
+    pub meals: Vec<u32>,
+}
+
+impl Elf {
+    pub fn total_calories(&self) -> u64 {
+        self.meals.iter().map(|&calories| calories as u64).sum()
+    }
+}
+
+/// Reads every elf left in the records, with its meals. Negative meals are rejected even by a
+/// lenient reader, which lets them through.
+pub fn collect_elves(reader: &mut CaloriesReader) -> Result<Vec<Elf>, ParseError> {
+    let mut elves = Vec::new();
+    loop {
+        let mut meals = Vec::new();
+        let has_elf = reader.read_elf(|line_number, meal, calories| {
+            let calories = u32::try_from(calories)
+                .map_err(|_| ParseError::whole_line(line_number, meal, "the calories of a meal cannot be negative"))?;
+            meals.push(calories);
+            Ok(())
+        })?;
+        if !has_elf {
+            return Ok(elves);
+        }
+
+        elves.push(Elf { index: elves.len() + 1, meals });
+    }
+}
+
+pub fn parse_elves(elves_calories_records: &str) -> Result<Vec<Elf>, ParseError> {
+    collect_elves(&mut CaloriesReader::new(elves_calories_records))
+}
+
+pub fn read_elves(filename: &str) -> Result<Vec<Elf>, Error> {
+    aoc_common::parse_file(filename, parse_elves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    #[test]
+    fn keeps_the_meals_of_each_elf() {
+        let elves = read_elves(EXAMPLE).unwrap();
+
+        assert_eq!(elves.len(), 5);
+        assert_eq!(elves[0], Elf { index: 1, meals: vec![1000, 2000, 3000] });
+        assert_eq!(elves[4], Elf { index: 5, meals: vec![10000] });
+    }
+
+    #[test]
+    fn sums_the_meals_of_an_elf() {
+        let totals: Vec<u64> = read_elves(EXAMPLE).unwrap().iter().map(Elf::total_calories).collect();
+
+        assert_eq!(totals, vec![6000, 4000, 11000, 24000, 10000]);
+    }
+}
@@ -0,0 +1,301 @@
+pub mod export;
+pub mod generator;
+pub mod inventory;
+pub mod rebalance;
+pub mod reference;
+pub mod report;
+
+use std::{cmp::Reverse, collections::BinaryHeap, iter::{Enumerate, Peekable}, num::IntErrorKind, str::Lines};
+
+use aoc_common::{Answer, Error, ParseError, Solution};
+
+
+/// Reads the records one line at a time, yielding the calories carried by each elf.
+///
+/// Blank lines separate the elves and every elf carries at least one meal; Windows line
+/// endings are accepted. A strict reader fails on the first meal that is not a non-negative
+/// number; a lenient one reads the records as this solution always did, counting such meals
+/// as 0 calories (negative ones are summed) and collapsing repeated blank lines, and keeps a
+/// warning for each of them instead.
+pub struct CaloriesReader<'a> {
+    lines: Peekable<Enumerate<Lines<'a>>>,
+    lenient: bool,
+    elves: usize,
+    warnings: Vec<ParseError>,
+}
+
+impl<'a> CaloriesReader<'a> {
+    pub fn new(elves_calories_records: &'a str) -> CaloriesReader<'a> {
+        CaloriesReader { lines: elves_calories_records.lines().enumerate().peekable(), lenient: false, elves: 0, warnings: Vec::new() }
+    }
+
+    pub fn lenient(elves_calories_records: &'a str) -> CaloriesReader<'a> {
+        CaloriesReader { lenient: true, ..CaloriesReader::new(elves_calories_records) }
+    }
+
+    pub fn warnings(&self) -> &[ParseError] {
+        &self.warnings
+    }
+
+    pub fn into_warnings(self) -> Vec<ParseError> {
+        self.warnings
+    }
+
+    /// Fails with `err` when strict, or keeps it as a warning and goes on with `lenient_value`.
+    fn reject<T>(&mut self, err: ParseError, lenient_value: T) -> Result<T, ParseError> {
+        if self.lenient {
+            self.warnings.push(err);
+            Ok(lenient_value)
+        } else {
+            Err(err)
+        }
+    }
+
+    fn parse_meal(&mut self, line_number: usize, meal: &str) -> Result<i32, ParseError> {
+        match meal.parse::<i32>() {
+            Ok(calories) if calories >= 0 => Ok(calories),
+            Ok(calories) => self.reject(ParseError::whole_line(line_number, meal, "the calories of a meal cannot be negative"), calories),
+            Err(err) if matches!(err.kind(), IntErrorKind::PosOverflow | IntErrorKind::NegOverflow) => {
+                self.reject(ParseError::whole_line(line_number, meal, "the calories of this meal overflow"), 0)
+            }
+            Err(_) => self.reject(ParseError::whole_line(line_number, meal, "expected the calories of a meal"), 0),
+        }
+    }
+
+    /// Hands each meal of the next elf to `on_meal` with its line, telling whether there was an elf left.
+    fn read_elf<F>(&mut self, mut on_meal: F) -> Result<bool, ParseError>
+    where
+        F: FnMut(usize, &str, i32) -> Result<(), ParseError>,
+    {
+        let mut first_blank_line = None;
+        while let Some(&(idx, _)) = self.lines.peek().filter(|(_, line)| line.is_empty()) {
+            first_blank_line.get_or_insert(idx + 1);
+            self.lines.next();
+        }
+
+        if self.lines.peek().is_none() {
+            if self.elves > 0 {
+                return Ok(false);
+            }
+            self.reject(ParseError::whole_line(1, "", "the records do not list any elf"), ())?;
+        } else if let Some(line_number) = first_blank_line {
+            self.reject(ParseError::whole_line(line_number, "", "blank line without an elf before it"), ())?;
+        }
+
+        while let Some((idx, meal)) = self.lines.next_if(|(_, line)| !line.is_empty()) {
+            let calories = self.parse_meal(idx + 1, meal)?;
+            on_meal(idx + 1, meal, calories)?;
+        }
+        self.lines.next();
+        self.elves += 1;
+
+        Ok(true)
+    }
+}
+
+impl Iterator for CaloriesReader<'_> {
+    type Item = Result<i32, ParseError>;
+
+    fn next(&mut self) -> Option<Result<i32, ParseError>> {
+        let mut calories: i32 = 0;
+        let elf = self.read_elf(|line_number, meal, meal_calories| {
+            calories = calories.checked_add(meal_calories)
+                .ok_or_else(|| ParseError::whole_line(line_number, meal, "the calories carried by this elf overflow"))?;
+            Ok(())
+        });
+
+        match elf {
+            Ok(true) => Some(Ok(calories)),
+            Ok(false) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+fn max_calories(calories_per_elves: &[i32]) -> i32 {
+    *calories_per_elves.iter().max().expect("Something goes wrong")
+}
+
+fn calculate_calories_per_elves(elves_calories_records: &str) -> Result<Vec<i32>, ParseError> {
+    CaloriesReader::new(elves_calories_records).collect()
+}
+
+pub fn parse_input(elves_calories_records: &str) -> Result<Vec<i32>, ParseError> {
+    calculate_calories_per_elves(elves_calories_records)
+}
+
+pub fn read_input(filename: &str) -> Result<Vec<i32>, Error> {
+    aoc_common::parse_file(filename, parse_input)
+}
+
+/// Keeps the `n` highest calories pushed so far in a min-heap, so the lowest of them is
+/// the one to evict when a higher one comes.
+struct TopCalories {
+    n: usize,
+    heap: BinaryHeap<Reverse<i32>>,
+}
+
+impl TopCalories {
+    fn new(n: usize) -> TopCalories {
+        TopCalories { n, heap: BinaryHeap::with_capacity(n) }
+    }
+
+    fn push(&mut self, calories: i32) {
+        if self.heap.len() < self.n {
+            self.heap.push(Reverse(calories));
+        } else if let Some(mut lowest) = self.heap.peek_mut() {
+            if lowest.0 < calories {
+                *lowest = Reverse(calories);
+            }
+        }
+    }
+
+    fn into_sorted_vec(self) -> Vec<i32> {
+        self.heap.into_sorted_vec().into_iter().map(|Reverse(calories)| calories).collect()
+    }
+}
+
+/// The `n` highest calories, highest first, in a single pass over `calories_per_elves`.
+pub fn top_n<I: IntoIterator<Item = i32>>(calories_per_elves: I, n: usize) -> Vec<i32> {
+    let mut top = TopCalories::new(n);
+    for calories in calories_per_elves {
+        top.push(calories);
+    }
+
+    top.into_sorted_vec()
+}
+
+/// Same as `top_n`, stopping at the first error of `calories_per_elves`.
+pub fn try_top_n<I>(calories_per_elves: I, n: usize) -> Result<Vec<i32>, ParseError>
+where
+    I: IntoIterator<Item = Result<i32, ParseError>>,
+{
+    let mut top = TopCalories::new(n);
+    for calories in calories_per_elves {
+        top.push(calories?);
+    }
+
+    Ok(top.into_sorted_vec())
+}
+
+/// Same as `top_n`, but reads the calories of each elf while walking the records.
+pub fn parse_top_n(elves_calories_records: &str, n: usize) -> Result<Vec<i32>, ParseError> {
+    try_top_n(CaloriesReader::new(elves_calories_records), n)
+}
+
+pub fn step_1(calories_per_elves: &[i32]) -> i32 {
+    max_calories(calories_per_elves)
+}
+
+pub fn step_2(calories_per_elves: &[i32]) -> i64 {
+    top_n(calories_per_elves.iter().copied(), 3).iter().map(|&calories| calories as i64).sum()
+}
+
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn solve_str(input: &str) -> Result<(i32, i64), ParseError> {
+    let parsed = parse_input(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn default_input(&self) -> &'static str {
+        DEFAULT_INPUT
+    }
+
+    fn step_1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_1(&parse_input(input)?).into())
+    }
+
+    fn step_2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_2(&parse_input(input)?).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    #[test]
+    fn calculates_the_calories_carried_by_each_elf() {
+        assert_eq!(read_input(EXAMPLE).unwrap(), vec![6000, 4000, 11000, 24000, 10000]);
+    }
+
+    #[test]
+    fn rejects_calories_overflowing_an_elf() {
+        let err = parse_input("1\n\n2147483647\n1\n").unwrap_err();
+
+        assert_eq!(err.line, 4);
+    }
+
+    #[test]
+    fn rejects_meals_that_are_not_calories() {
+        let err = parse_input("1000\n12a4\n\n2000\n").unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (2, "12a4"));
+
+        assert_eq!(parse_input("1000\n\n-20\n").unwrap_err().line, 3);
+        assert_eq!(parse_input("99999999999\n").unwrap_err().message, "the calories of this meal overflow");
+    }
+
+    #[test]
+    fn accepts_windows_line_endings() {
+        assert_eq!(parse_input("1000\r\n2000\r\n\r\n3000\r\n").unwrap(), vec![3000, 3000]);
+    }
+
+    #[test]
+    fn rejects_elves_without_meals() {
+        assert_eq!(parse_input("1000\n\n\n2000\n").unwrap_err().line, 3);
+        assert_eq!(parse_input("\n1000\n").unwrap_err().line, 1);
+        assert_eq!(parse_input("").unwrap_err().line, 1);
+        assert_eq!(parse_input("1000\n\n\n").unwrap(), vec![1000]);
+    }
+
+    #[test]
+    fn lenient_reader_warns_instead_of_failing() {
+        let records = "1000\n12a4\n\n\n-20\n30\n";
+        let mut reader = CaloriesReader::lenient(records);
+        let calories: Vec<i32> = (&mut reader).collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(calories, vec![1000, 10]);
+        let lines: Vec<usize> = reader.warnings().iter().map(|warning| warning.line).collect();
+        assert_eq!(lines, vec![2, 4, 5]);
+
+        let err = inventory::collect_elves(&mut CaloriesReader::lenient(records)).unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (5, "the calories of a meal cannot be negative"));
+    }
+
+    #[test]
+    fn keeps_the_highest_calories_first() {
+        assert_eq!(top_n([4000, 24000, 6000, 10000, 11000], 3), vec![24000, 11000, 10000]);
+        assert_eq!(top_n([4000, 24000], 3), vec![24000, 4000]);
+        assert_eq!(top_n([4000, 24000], 0), vec![]);
+    }
+
+    #[test]
+    fn finds_the_top_elves_while_reading_the_records() {
+        let records = aoc_common::read_input(EXAMPLE).unwrap();
+
+        assert_eq!(parse_top_n(&records, 2).unwrap(), vec![24000, 11000]);
+        assert_eq!(parse_top_n("1\n\n2147483647\n1\n", 1).unwrap_err().line, 4);
+    }
+
+    #[test]
+    fn step_1_finds_the_elf_carrying_the_most_calories() {
+        assert_eq!(step_1(&read_input(EXAMPLE).unwrap()), 24000);
+    }
+
+    #[test]
+    fn step_2_sums_the_calories_of_the_top_three_elves() {
+        assert_eq!(step_2(&read_input(EXAMPLE).unwrap()), 45000);
+        assert_eq!(step_2(&[i32::MAX, i32::MAX, i32::MAX]), 3 * i32::MAX as i64);
+    }
+}
@@ -1,56 +1,114 @@
-use std::fs;
+use std::{path::Path, process::ExitCode};
 
+use aoc_common::{input_name, Error, InputArgs, ParseError};
+use clap::Parser;
+use day01::{export::Format, CaloriesReader};
 
-fn read_input(filename: &str) -> String {
-    fs::read_to_string(filename).expect("Cannot read the input")
-}
+/// Day 1: Calorie Counting
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
 
-fn split_meals_by_elves(elves_meals: &String) -> Vec<String> {
-    elves_meals.split("\n\n").map(|meals| String::from(meals)).collect()
-}   
+    /// Print the calories of the N elves carrying the most, instead of the two parts
+    #[arg(long, value_name = "N")]
+    top: Option<usize>,
 
-fn calculate_total_calories_per_elves(elves_meals: &Vec<String>) -> Vec<i32> {
-    let mut calories_per_elves = Vec::new();
+    /// Print the calories of every elf and their distribution; --top sets how many top carriers are listed
+    #[arg(long)]
+    report: bool,
 
-    for chunck_meals in elves_meals {
-        let calories: i32 = chunck_meals.split("\n").map(|meal| meal.parse::<i32>().unwrap_or_default()).sum();
-        calories_per_elves.push(calories)
-    }
+    /// Print the calories of every elf in this format, instead of the two parts
+    #[arg(long, value_enum, value_name = "FORMAT", conflicts_with_all = ["top", "report"])]
+    export: Option<Format>,
 
-    calories_per_elves
-}
+    /// Draw a histogram of the calories carried by the elves, instead of the two parts
+    #[arg(long, conflicts_with_all = ["top", "report", "export"])]
+    histogram: bool,
 
-fn max_calories(calories_per_elves: &Vec<i32>) -> i32 {
-    *calories_per_elves.iter().max().expect("Something goes wrong")
-}
+    /// Number of buckets of the histogram, fewer when the calories span fewer values
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u16).range(1..))]
+    buckets: u16,
 
-fn calculate_calories_per_elves(elves_calories_records: &String) -> Vec<i32> {
-    let elves_meals = split_meals_by_elves(&elves_calories_records);
+    /// Propose meals to move between elves so that the heaviest load is as light as possible
+    #[arg(long, conflicts_with_all = ["top", "report", "export", "histogram"])]
+    rebalance: bool,
 
-    calculate_total_calories_per_elves(&elves_meals)
+    /// Count the meals that are not calories as 0 and go on, printing a warning for each of them
+    #[arg(long)]
+    lenient: bool,
 }
 
-fn step_1() -> i32 {
-    let calories_records = read_input("input.txt");
-    let calories_per_elves = calculate_calories_per_elves(&calories_records);
+/// Reads the records with the reader chosen on the command line, printing its warnings.
+fn load<T, F>(cli: &Cli, read: F) -> Result<T, Error>
+where
+    F: FnOnce(&mut CaloriesReader) -> Result<T, ParseError>,
+{
+    let (value, warnings) = cli.input.load(day01::DEFAULT_INPUT, |input| {
+        let mut reader = if cli.lenient { CaloriesReader::lenient(input) } else { CaloriesReader::new(input) };
+        let value = read(&mut reader)?;
+        Ok((value, reader.into_warnings()))
+    })?;
+
+    let filename = input_name(Path::new(cli.input.filename(day01::DEFAULT_INPUT)));
+    for warning in warnings {
+        eprintln!("{}", warning.with_file(filename).render_warning());
+    }
 
-    max_calories(&calories_per_elves)
+    Ok(value)
 }
 
-fn sum_max_three_calories(calories_per_elves: &Vec<i32>) -> i32 {
-    calories_per_elves.iter().rev().take(3).sum()
-}
+fn run(cli: &Cli) -> Result<(), Error> {
+    if cli.report {
+        let elves = load(cli, day01::inventory::collect_elves)?;
+        print!("{}", day01::report::render(&elves, cli.top.unwrap_or(3)));
 
-fn step_2() -> i32 {
-    let calories_records = read_input("input.txt");
-    let mut calories_per_elves = calculate_calories_per_elves(&calories_records);
-    calories_per_elves.sort();
+        return Ok(());
+    }
 
-    sum_max_three_calories(&calories_per_elves)
-}
+    if cli.rebalance {
+        let elves = load(cli, day01::inventory::collect_elves)?;
+        let plan = day01::rebalance::plan(&elves);
+
+        println!("Heaviest load before: {}", plan.max_before);
+        println!("Heaviest load after: {}", plan.max_after);
+        println!("Transfers: {}", plan.transfers.len());
+        for transfer in &plan.transfers {
+            println!("  {} calories from elf {} to elf {}", transfer.calories, transfer.from, transfer.to);
+        }
+
+        return Ok(());
+    }
 
-fn main() {
-    assert_eq!(step_1(), 69912);
+    if let Some(n) = cli.top {
+        let top = load(cli, |reader| day01::try_top_n(reader, n))?;
+
+        for (rank, calories) in top.iter().enumerate() {
+            println!("#{}: {}", rank + 1, calories);
+        }
+        println!("Total: {}", top.iter().map(|&calories| calories as i64).sum::<i64>());
+
+        return Ok(());
+    }
+
+    let calories_per_elves: Vec<i32> = load(cli, |reader| reader.collect())?;
+
+    if let Some(format) = cli.export {
+        print!("{}", day01::export::export(&calories_per_elves, format));
+        return Ok(());
+    }
+
+    if cli.histogram {
+        print!("{}", day01::export::histogram(&calories_per_elves, cli.buckets as usize));
+        return Ok(());
+    }
+
+    println!("Part 1: {}", day01::step_1(&calories_per_elves));
+    println!("Part 2: {}", day01::step_2(&calories_per_elves));
+
+    Ok(())
+}
 
-    assert_eq!(step_2(), 208180);
+fn main() -> ExitCode {
+    aoc_common::exit_code(run(&Cli::parse()))
 }
@@ -0,0 +1,270 @@
+//! Moves meals between elves so that the heaviest load gets as light as possible.
+//!
+//! This is multiway number partitioning, so the planner is a heuristic: the meals are dealt
+//! with the longest processing time rule (heaviest meal to the lightest elf), then single
+//! moves and swaps out of the heaviest elf, with the lightest elves, are tried until none
+//! of them helps. The same
+//! search also runs from the current loads, and the better plan wins, the one with fewer
+//! transfers on a tie.
+
+use std::{cmp::Reverse, collections::{BTreeSet, BinaryHeap, HashMap}};
+
+use crate::inventory::Elf;
+
+/// A meal handed from an elf to another, the elves being named by their `Elf::index`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transfer {
+    pub calories: u32,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug)]
+pub struct Plan {
+    pub max_before: u64,
+    pub max_after: u64,
+    pub transfers: Vec<Transfer>,
+}
+
+/// Number of the lightest elves the meals of the heaviest one may be swapped with.
+const SWAP_CANDIDATES: usize = 32;
+
+/// The calories of every meal, and the elf (by position) carrying it.
+struct Meals {
+    calories: Vec<u32>,
+    owners: Vec<usize>,
+    elves: usize,
+}
+
+impl Meals {
+    fn new(elves: &[Elf]) -> Meals {
+        let (calories, owners) = elves.iter()
+            .enumerate()
+            .flat_map(|(owner, elf)| elf.meals.iter().map(move |&calories| (calories, owner)))
+            .unzip();
+
+        Meals { calories, owners, elves: elves.len() }
+    }
+
+    fn loads(&self, assignment: &[usize]) -> Vec<u64> {
+        let mut loads = vec![0; self.elves];
+        for (&calories, &elf) in self.calories.iter().zip(assignment) {
+            loads[elf] += calories as u64;
+        }
+
+        loads
+    }
+
+    fn transfers(&self, assignment: &[usize]) -> usize {
+        self.owners.iter().zip(assignment).filter(|(owner, elf)| owner != elf).count()
+    }
+
+    /// Longest processing time rule: every meal, heaviest first, goes to the lightest elf.
+    fn deal(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.calories.len()).collect();
+        order.sort_by_key(|&meal| Reverse(self.calories[meal]));
+
+        let mut lightest: BinaryHeap<Reverse<(u64, usize)>> = (0..self.elves).map(|elf| Reverse((0, elf))).collect();
+        let mut assignment = vec![0; self.calories.len()];
+        for meal in order {
+            let Reverse((load, elf)) = lightest.pop().unwrap();
+            lightest.push(Reverse((load + self.calories[meal] as u64, elf)));
+            assignment[meal] = elf;
+        }
+
+        self.relabel(&assignment)
+    }
+
+    /// Dealing does not care who carried what: hand every group of meals to the elf that
+    /// already carries most of its calories, so that fewer meals have to move.
+    fn relabel(&self, assignment: &[usize]) -> Vec<usize> {
+        let mut kept: HashMap<(usize, usize), u64> = HashMap::new();
+        for ((&group, &owner), &calories) in assignment.iter().zip(&self.owners).zip(&self.calories) {
+            *kept.entry((group, owner)).or_default() += calories as u64;
+        }
+
+        let mut pairs: Vec<((usize, usize), u64)> = kept.into_iter().collect();
+        pairs.sort_by_key(|&((group, elf), kept)| (Reverse(kept), group, elf));
+
+        let mut elf_of_group = vec![None; self.elves];
+        let mut taken = vec![false; self.elves];
+        for ((group, elf), _) in pairs {
+            if elf_of_group[group].is_none() && !taken[elf] {
+                elf_of_group[group] = Some(elf);
+                taken[elf] = true;
+            }
+        }
+
+        // The groups holding no meal of a free elf get the free elves left.
+        let mut free = (0..self.elves).filter(|&elf| !taken[elf]);
+        for elf in elf_of_group.iter_mut().filter(|elf| elf.is_none()) {
+            *elf = free.next();
+        }
+
+        assignment.iter().map(|&group| elf_of_group[group].unwrap()).collect()
+    }
+
+    /// Lightens the heaviest elf with a move or a swap as long as the elf on the other side
+    /// stays lighter than it was; every step lowers the sorted loads, so the search ends.
+    /// A move goes at best to the lightest elf, and swaps are only tried with the
+    /// `SWAP_CANDIDATES` lightest elves.
+    fn improve(&self, assignment: &mut [usize]) {
+        let mut loads = self.loads(assignment);
+        let mut by_load: BTreeSet<(u64, usize)> = loads.iter().enumerate().map(|(elf, &load)| (load, elf)).collect();
+        let mut meals_of = vec![Vec::new(); self.elves];
+        for (meal, &elf) in assignment.iter().enumerate() {
+            meals_of[elf].push(meal);
+        }
+
+        loop {
+            let &(heaviest_load, heaviest) = by_load.last().unwrap();
+            let lightest: Vec<usize> = by_load.iter().map(|&(_, elf)| elf).filter(|&elf| elf != heaviest).take(SWAP_CANDIDATES).collect();
+            let mut best_max = heaviest_load;
+            let mut best: Option<(usize, Option<usize>, usize)> = None;
+
+            for &meal in &meals_of[heaviest] {
+                let calories = self.calories[meal] as u64;
+
+                if let Some(&elf) = lightest.first() {
+                    let new_max = (heaviest_load - calories).max(loads[elf] + calories);
+                    if new_max < best_max {
+                        best_max = new_max;
+                        best = Some((meal, None, elf));
+                    }
+                }
+
+                for &elf in &lightest {
+                    for &other in &meals_of[elf] {
+                        let other_calories = self.calories[other] as u64;
+                        if other_calories >= calories {
+                            continue;
+                        }
+
+                        let new_max = (heaviest_load - calories + other_calories).max(loads[elf] - other_calories + calories);
+                        if new_max < best_max {
+                            best_max = new_max;
+                            best = Some((meal, Some(other), elf));
+                        }
+                    }
+                }
+            }
+
+            let Some((meal, other, elf)) = best else {
+                return;
+            };
+
+            by_load.remove(&(loads[heaviest], heaviest));
+            by_load.remove(&(loads[elf], elf));
+            move_meal(assignment, &mut meals_of, meal, elf);
+            loads[heaviest] -= self.calories[meal] as u64;
+            loads[elf] += self.calories[meal] as u64;
+            if let Some(other) = other {
+                move_meal(assignment, &mut meals_of, other, heaviest);
+                loads[elf] -= self.calories[other] as u64;
+                loads[heaviest] += self.calories[other] as u64;
+            }
+            by_load.insert((loads[heaviest], heaviest));
+            by_load.insert((loads[elf], elf));
+        }
+    }
+
+    fn max_load(&self, assignment: &[usize]) -> u64 {
+        self.loads(assignment).into_iter().max().unwrap_or(0)
+    }
+}
+
+fn move_meal(assignment: &mut [usize], meals_of: &mut [Vec<usize>], meal: usize, to: usize) {
+    let from = &mut meals_of[assignment[meal]];
+    from.swap_remove(from.iter().position(|&other| other == meal).unwrap());
+    meals_of[to].push(meal);
+    assignment[meal] = to;
+}
+
+pub fn plan(elves: &[Elf]) -> Plan {
+    let meals = Meals::new(elves);
+    let max_before = meals.max_load(&meals.owners);
+    if elves.is_empty() {
+        return Plan { max_before, max_after: max_before, transfers: Vec::new() };
+    }
+
+    let mut from_current = meals.owners.clone();
+    meals.improve(&mut from_current);
+    let mut dealt = meals.deal();
+    meals.improve(&mut dealt);
+
+    let assignment = [from_current, dealt].into_iter()
+        .min_by_key(|assignment| (meals.max_load(assignment), meals.transfers(assignment)))
+        .unwrap();
+
+    let transfers = (0..meals.calories.len())
+        .filter(|&meal| assignment[meal] != meals.owners[meal])
+        .map(|meal| Transfer {
+            calories: meals.calories[meal],
+            from: elves[meals.owners[meal]].index,
+            to: elves[assignment[meal]].index,
+        })
+        .collect();
+
+    Plan { max_before, max_after: meals.max_load(&assignment), transfers }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::read_elves;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    /// The loads of the elves once the transfers are done, checking every moved meal was there.
+    fn apply(elves: &[Elf], transfers: &[Transfer]) -> Vec<u64> {
+        let mut meals: Vec<Vec<u32>> = elves.iter().map(|elf| elf.meals.clone()).collect();
+        for transfer in transfers {
+            let from = &mut meals[transfer.from - 1];
+            let position = from.iter().position(|&calories| calories == transfer.calories).expect("the meal is not there");
+            from.remove(position);
+            meals[transfer.to - 1].push(transfer.calories);
+        }
+
+        meals.iter().map(|meals| meals.iter().map(|&calories| calories as u64).sum()).collect()
+    }
+
+    #[test]
+    fn balances_the_example_perfectly() {
+        let elves = read_elves(EXAMPLE).unwrap();
+        let plan = plan(&elves);
+
+        assert_eq!((plan.max_before, plan.max_after), (24000, 11000));
+        assert_eq!(apply(&elves, &plan.transfers), vec![11000; 5]);
+    }
+
+    #[test]
+    fn moves_nothing_when_the_elves_are_balanced() {
+        let elves = vec![Elf { index: 1, meals: vec![3, 2] }, Elf { index: 2, meals: vec![5] }];
+        let plan = plan(&elves);
+
+        assert_eq!((plan.max_before, plan.max_after), (5, 5));
+        assert!(plan.transfers.is_empty());
+    }
+
+    #[test]
+    fn swaps_meals_when_a_move_does_not_help() {
+        let elves = vec![Elf { index: 1, meals: vec![6, 4] }, Elf { index: 2, meals: vec![5, 3] }];
+        let plan = plan(&elves);
+
+        assert_eq!(plan.max_after, 9);
+        assert_eq!(apply(&elves, &plan.transfers), vec![9, 9]);
+    }
+
+    #[test]
+    fn rebalances_thousands_of_elves() {
+        let elves: Vec<Elf> = (1..=2000)
+            .map(|index| Elf { index, meals: (0..index % 7 + 1).map(|meal| ((index * 7919 + meal * 104729) % 5000 + 1) as u32).collect() })
+            .collect();
+        let plan = plan(&elves);
+
+        let loads = apply(&elves, &plan.transfers);
+        let total: u64 = loads.iter().sum();
+        assert_eq!(loads.into_iter().max(), Some(plan.max_after));
+        assert!(plan.max_after < plan.max_before && plan.max_after >= total.div_ceil(elves.len() as u64));
+    }
+}
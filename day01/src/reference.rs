@@ -0,0 +1,31 @@
+use aoc_common::ParseError;
+
+pub fn step_1(calories_per_elves: &[i32]) -> i32 {
+    let mut max_calories = calories_per_elves[0];
+    for &calories in calories_per_elves {
+        if calories > max_calories {
+            max_calories = calories;
+        }
+    }
+
+    max_calories
+}
+
+pub fn step_2(calories_per_elves: &[i32]) -> i64 {
+    let mut remaining = calories_per_elves.to_vec();
+    let mut total = 0;
+
+    for _ in 0..3.min(remaining.len()) {
+        let max_calories = step_1(&remaining);
+        let position = remaining.iter().position(|&calories| calories == max_calories).unwrap();
+        total += remaining.remove(position) as i64;
+    }
+
+    total
+}
+
+pub fn solve_str(input: &str) -> Result<(i32, i64), ParseError> {
+    let parsed = crate::parse_input(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
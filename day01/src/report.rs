@@ -0,0 +1,137 @@
+use std::fmt::Write;
+
+use crate::inventory::Elf;
+
+/// Distribution of the calories carried by the elves.
+#[derive(Debug, PartialEq)]
+pub struct Statistics {
+    pub elves: usize,
+    pub meals: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+}
+
+/// Percentiles shown in the report.
+const PERCENTILES: [u32; 4] = [10, 25, 75, 90];
+
+/// Nearest-rank percentile of calories already sorted in ascending order.
+pub fn percentile(sorted_calories: &[u64], percent: u32) -> u64 {
+    let rank = (sorted_calories.len() * percent as usize).div_ceil(100).max(1);
+
+    sorted_calories[rank - 1]
+}
+
+fn sorted_totals(elves: &[Elf]) -> Vec<u64> {
+    let mut totals: Vec<u64> = elves.iter().map(Elf::total_calories).collect();
+    totals.sort();
+
+    totals
+}
+
+pub fn statistics(elves: &[Elf]) -> Option<Statistics> {
+    let totals = sorted_totals(elves);
+    if totals.is_empty() {
+        return None;
+    }
+
+    let middle = totals.len() / 2;
+    let median = if totals.len() % 2 == 1 {
+        totals[middle] as f64
+    } else {
+        (totals[middle - 1] + totals[middle]) as f64 / 2.0
+    };
+
+    Some(Statistics {
+        elves: totals.len(),
+        meals: elves.iter().map(|elf| elf.meals.len()).sum(),
+        min: totals[0],
+        max: totals[totals.len() - 1],
+        mean: totals.iter().sum::<u64>() as f64 / totals.len() as f64,
+        median,
+    })
+}
+
+/// The `n` elves carrying the most calories, the first elf in the records winning ties.
+pub fn top_carriers(elves: &[Elf], n: usize) -> Vec<&Elf> {
+    let mut carriers: Vec<&Elf> = elves.iter().collect();
+    carriers.sort_by_key(|elf| std::cmp::Reverse(elf.total_calories()));
+    carriers.truncate(n);
+
+    carriers
+}
+
+pub fn render(elves: &[Elf], top: usize) -> String {
+    let mut report = String::new();
+
+    writeln!(report, "{:>5} {:>6} {:>9}", "Elf", "Meals", "Calories").unwrap();
+    for elf in elves {
+        writeln!(report, "{:>5} {:>6} {:>9}", elf.index, elf.meals.len(), elf.total_calories()).unwrap();
+    }
+
+    let Some(stats) = statistics(elves) else {
+        return report;
+    };
+    let totals = sorted_totals(elves);
+
+    writeln!(report).unwrap();
+    writeln!(report, "Elves: {}", stats.elves).unwrap();
+    writeln!(report, "Meals: {}", stats.meals).unwrap();
+    writeln!(report, "Min: {}", stats.min).unwrap();
+    writeln!(report, "Max: {}", stats.max).unwrap();
+    writeln!(report, "Mean: {:.1}", stats.mean).unwrap();
+    writeln!(report, "Median: {:.1}", stats.median).unwrap();
+    for percent in PERCENTILES {
+        writeln!(report, "p{}: {}", percent, percentile(&totals, percent)).unwrap();
+    }
+
+    writeln!(report).unwrap();
+    writeln!(report, "Top carriers:").unwrap();
+    for (rank, elf) in top_carriers(elves, top).iter().enumerate() {
+        writeln!(report, "{:>3}. elf {} with {} calories", rank + 1, elf.index, elf.total_calories()).unwrap();
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::read_elves;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    #[test]
+    fn describes_the_calories_distribution() {
+        let stats = statistics(&read_elves(EXAMPLE).unwrap()).unwrap();
+
+        assert_eq!(stats, Statistics { elves: 5, meals: 10, min: 4000, max: 24000, mean: 11000.0, median: 10000.0 });
+    }
+
+    #[test]
+    fn averages_the_two_middle_elves_for_the_median() {
+        let elves = vec![Elf { index: 1, meals: vec![1] }, Elf { index: 2, meals: vec![4] }];
+
+        assert_eq!(statistics(&elves).unwrap().median, 2.5);
+        assert_eq!(statistics(&[]), None);
+    }
+
+    #[test]
+    fn computes_nearest_rank_percentiles() {
+        let totals = [4000, 6000, 10000, 11000, 24000];
+
+        assert_eq!(percentile(&totals, 10), 4000);
+        assert_eq!(percentile(&totals, 25), 6000);
+        assert_eq!(percentile(&totals, 75), 11000);
+        assert_eq!(percentile(&totals, 100), 24000);
+    }
+
+    #[test]
+    fn names_the_top_carriers() {
+        let elves = read_elves(EXAMPLE).unwrap();
+        let indexes: Vec<usize> = top_carriers(&elves, 3).iter().map(|elf| elf.index).collect();
+
+        assert_eq!(indexes, vec![4, 3, 5]);
+    }
+}
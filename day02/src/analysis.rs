@@ -0,0 +1,155 @@
+//! What the guide is worth under its two interpretations, compared with the best score
+//! that could be made knowing the opponent's shapes.
+
+use std::fmt::Write;
+
+use crate::{GuideLine, Outcome, Round, Shape};
+
+/// The shape scoring the most against `opponent`.
+pub fn best_response(opponent: Shape) -> Shape {
+    Shape::ALL.into_iter()
+        .max_by_key(|&you| Round { opponent, you }.score())
+        .unwrap()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpretation {
+    /// The second column is your shape, as in part 1.
+    Shapes,
+    /// The second column is the outcome of the round, as in part 2.
+    Outcomes,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundAnalysis {
+    /// Position of the round in the guide, starting from 1.
+    pub round: usize,
+    pub opponent: Shape,
+    pub best: Round,
+    pub as_shapes: Round,
+    pub as_outcomes: Round,
+}
+
+impl RoundAnalysis {
+    pub fn played(&self, interpretation: Interpretation) -> Round {
+        match interpretation {
+            Interpretation::Shapes => self.as_shapes,
+            Interpretation::Outcomes => self.as_outcomes,
+        }
+    }
+
+    /// Points left on the table by following the guide read this way.
+    pub fn points_lost(&self, interpretation: Interpretation) -> u32 {
+        self.best.score() - self.played(interpretation).score()
+    }
+}
+
+#[derive(Debug)]
+pub struct Analysis {
+    pub rounds: Vec<RoundAnalysis>,
+}
+
+impl Analysis {
+    pub fn best_score(&self) -> u32 {
+        self.rounds.iter().map(|round| round.best.score()).sum()
+    }
+
+    pub fn score(&self, interpretation: Interpretation) -> u32 {
+        self.rounds.iter().map(|round| round.played(interpretation).score()).sum()
+    }
+
+    /// The `n` rounds losing the most points, the earliest first on a tie.
+    pub fn costliest(&self, interpretation: Interpretation, n: usize) -> Vec<&RoundAnalysis> {
+        let mut rounds: Vec<&RoundAnalysis> = self.rounds.iter().filter(|round| round.points_lost(interpretation) > 0).collect();
+        rounds.sort_by_key(|round| std::cmp::Reverse(round.points_lost(interpretation)));
+        rounds.truncate(n);
+
+        rounds
+    }
+}
+
+pub fn analyse(games: &[GuideLine]) -> Analysis {
+    let rounds = games.iter()
+        .enumerate()
+        .map(|(idx, game)| RoundAnalysis {
+            round: idx + 1,
+            opponent: game.opponent,
+            best: Round { opponent: game.opponent, you: best_response(game.opponent) },
+            as_shapes: Round { opponent: game.opponent, you: game.you },
+            as_outcomes: Round::with_outcome(game.opponent, game.outcome),
+        })
+        .collect();
+
+    Analysis { rounds }
+}
+
+fn describe(outcome: Outcome) -> &'static str {
+    match outcome {
+        Outcome::Lose => "lose",
+        Outcome::Draw => "draw",
+        Outcome::Win => "win",
+    }
+}
+
+pub fn render(analysis: &Analysis, worst: usize) -> String {
+    let best = analysis.best_score() as i64;
+    let as_shapes = analysis.score(Interpretation::Shapes) as i64;
+    let as_outcomes = analysis.score(Interpretation::Outcomes) as i64;
+
+    let mut report = String::new();
+    writeln!(report, "Best responses:       {}", best).unwrap();
+    writeln!(report, "Guide as shapes:      {} ({:+})", as_shapes, as_shapes - best).unwrap();
+    writeln!(report, "Guide as outcomes:    {} ({:+})", as_outcomes, as_outcomes - best).unwrap();
+    writeln!(report, "Outcomes over shapes: {:+}", as_outcomes - as_shapes).unwrap();
+
+    for (interpretation, title) in [(Interpretation::Shapes, "shapes"), (Interpretation::Outcomes, "outcomes")] {
+        writeln!(report).unwrap();
+        writeln!(report, "Rounds losing the most points as {}:", title).unwrap();
+        for round in analysis.costliest(interpretation, worst) {
+            let played = round.played(interpretation);
+            writeln!(
+                report,
+                "  round {}: {:?} against {:?} ({}) scores {}, {:?} would score {} (-{})",
+                round.round, played.you, round.opponent, describe(played.outcome()), played.score(),
+                round.best.you, round.best.score(), round.points_lost(interpretation)
+            ).unwrap();
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    #[test]
+    fn winning_is_always_the_best_response() {
+        for opponent in Shape::ALL {
+            assert_eq!(best_response(opponent), opponent.loses_to());
+        }
+    }
+
+    #[test]
+    fn compares_the_interpretations_with_the_best_score() {
+        let analysis = analyse(&read_input(EXAMPLE).unwrap());
+
+        assert_eq!(analysis.best_score(), 24);
+        assert_eq!(analysis.score(Interpretation::Shapes), 15);
+        assert_eq!(analysis.score(Interpretation::Outcomes), 12);
+    }
+
+    #[test]
+    fn finds_the_rounds_losing_the_most_points() {
+        let analysis = analyse(&read_input(EXAMPLE).unwrap());
+        let costliest = |interpretation| -> Vec<(usize, u32)> {
+            analysis.costliest(interpretation, 5).iter().map(|round| (round.round, round.points_lost(interpretation))).collect()
+        };
+
+        assert_eq!(costliest(Interpretation::Shapes), vec![(2, 8), (3, 1)]);
+        assert_eq!(costliest(Interpretation::Outcomes), vec![(2, 8), (1, 4)]);
+    }
+}
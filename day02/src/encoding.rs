@@ -0,0 +1,138 @@
+//! What the symbols of the guide stand for.
+//!
+//! A symbol stands for one of three choices, which is a shape for the opponent's column,
+//! and for the second column either your shape (part 1) or the outcome (part 2): the first
+//! choice is rock or losing, the second paper or a draw, the third scissors or winning.
+//! Rule sets read the guide through the same tokeniser with their own symbols.
+
+use aoc_common::ParseError;
+
+const SHAPE_WORDS: [&str; 3] = ["rock", "paper", "scissors"];
+pub(crate) const OUTCOME_WORDS: [&str; 3] = ["lose", "draw", "win"];
+
+#[derive(Debug, Clone)]
+pub struct Encoding {
+    opponent: Vec<(String, usize)>,
+    response: Vec<(String, usize)>,
+}
+
+impl Default for Encoding {
+    /// The puzzle's encoding, `A`, `B`, `C` and `X`, `Y`, `Z`, plus the names of the shapes
+    /// in both columns and of the outcomes in the second one.
+    fn default() -> Encoding {
+        let symbols = |letters: [&str; 3], words: &[[&str; 3]]| -> Vec<(String, usize)> {
+            std::iter::once(letters).chain(words.iter().copied())
+                .flat_map(|symbols| symbols.into_iter().enumerate().map(|(choice, symbol)| (String::from(symbol), choice)))
+                .collect()
+        };
+
+        Encoding {
+            opponent: symbols(["A", "B", "C"], &[SHAPE_WORDS]),
+            response: symbols(["X", "Y", "Z"], &[SHAPE_WORDS, OUTCOME_WORDS]),
+        }
+    }
+}
+
+fn insert(symbols: &mut Vec<(String, usize)>, symbol: &str, choice: usize) {
+    symbols.retain(|(other, _)| other != symbol);
+    symbols.push((String::from(symbol), choice));
+}
+
+/// Words match whatever their case, single letters only as written.
+fn find(symbols: &[(String, usize)], token: &str) -> Option<usize> {
+    symbols.iter()
+        .find(|(symbol, _)| symbol == token || (symbol.len() > 1 && symbol.eq_ignore_ascii_case(token)))
+        .map(|&(_, choice)| choice)
+}
+
+fn expected(symbols: &[(String, usize)]) -> String {
+    let symbols: Vec<&str> = symbols.iter().map(|(symbol, _)| symbol.as_str()).collect();
+
+    format!("expected one of {}", symbols.join(", "))
+}
+
+impl Encoding {
+    /// Reads the opponent's column with `opponent` and the second one with `response`, each a
+    /// list of symbols with the choice they stand for; when a symbol is listed twice, the first wins.
+    pub fn from_symbols(opponent: Vec<(String, usize)>, response: Vec<(String, usize)>) -> Encoding {
+        Encoding { opponent, response }
+    }
+
+    /// The default encoding, changed by a list of `SYMBOL=meaning` like `X=rock,Y=paper,Z=scissors`;
+    /// a symbol mapped to a shape means it in both columns, one mapped to an outcome only in the second.
+    pub fn with_mapping(mapping: &str) -> Result<Encoding, String> {
+        let mut encoding = Encoding::default();
+
+        for entry in mapping.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (symbol, meaning) = entry.split_once('=')
+                .ok_or_else(|| format!("expected `SYMBOL=meaning` in the mapping, found `{}`", entry))?;
+            let (symbol, meaning) = (symbol.trim(), meaning.trim().to_lowercase());
+            if symbol.is_empty() || symbol.contains(char::is_whitespace) {
+                return Err(format!("invalid symbol `{}` in the mapping", symbol));
+            }
+
+            if let Some(choice) = SHAPE_WORDS.iter().position(|&word| word == meaning) {
+                insert(&mut encoding.opponent, symbol, choice);
+                insert(&mut encoding.response, symbol, choice);
+            } else if let Some(choice) = OUTCOME_WORDS.iter().position(|&word| word == meaning) {
+                insert(&mut encoding.response, symbol, choice);
+            } else {
+                return Err(format!("`{}` means `{}`, expected one of {}, {}", symbol, meaning, SHAPE_WORDS.join(", "), OUTCOME_WORDS.join(", ")));
+            }
+        }
+
+        Ok(encoding)
+    }
+
+    /// Splits a line of the guide in its two columns, separated by any amount of whitespace,
+    /// and reads the choice each of them stands for.
+    pub fn parse_line(&self, line_number: usize, game: &str) -> Result<(usize, usize), ParseError> {
+        let mut columns = game.split_whitespace();
+        let (Some(opponent), Some(response), None) = (columns.next(), columns.next(), columns.next()) else {
+            return Err(ParseError::whole_line(line_number, game, "expected the opponent move and yours separated by whitespace"));
+        };
+
+        let opponent_choice = find(&self.opponent, opponent)
+            .ok_or_else(|| ParseError::in_line(line_number, game, opponent, expected(&self.opponent)))?;
+        let response_choice = find(&self.response, response)
+            .ok_or_else(|| ParseError::in_line(line_number, game, response, expected(&self.response)))?;
+
+        Ok((opponent_choice, response_choice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_letters_and_words_separated_by_any_whitespace() {
+        let encoding = Encoding::default();
+
+        assert_eq!(encoding.parse_line(1, "A Y").unwrap(), (0, 1));
+        assert_eq!(encoding.parse_line(1, "  Rock \t Paper ").unwrap(), (0, 1));
+        assert_eq!(encoding.parse_line(1, "scissors win").unwrap(), (2, 2));
+    }
+
+    #[test]
+    fn points_at_the_column_it_cannot_read() {
+        let encoding = Encoding::default();
+
+        let err = encoding.parse_line(3, "A  lizard").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 4, "lizard"));
+        assert!(encoding.parse_line(1, "x Y").is_err());
+        assert!(encoding.parse_line(1, "win Y").is_err());
+        assert!(encoding.parse_line(1, "A Y Z").is_err());
+    }
+
+    #[test]
+    fn maps_symbols_to_other_meanings() {
+        let encoding = Encoding::with_mapping("X=paper, Y=scissors, Z=rock, L=lose").unwrap();
+
+        assert_eq!(encoding.parse_line(1, "A X").unwrap(), (0, 1));
+        assert_eq!(encoding.parse_line(1, "Z L").unwrap(), (0, 0));
+        assert!(encoding.parse_line(1, "L L").is_err());
+        assert!(Encoding::with_mapping("X=lizard").is_err());
+        assert!(Encoding::with_mapping("X").is_err());
+    }
+}
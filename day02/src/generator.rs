@@ -0,0 +1,23 @@
+use std::fmt::Write;
+
+use clap::Args;
+use rand::Rng;
+
+#[derive(Args, Debug, Clone)]
+pub struct Params {
+    /// Number of rounds in the strategy guide
+    #[arg(long, default_value_t = 2500)]
+    pub rounds: usize,
+}
+
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, params: &Params) -> String {
+    let mut input = String::new();
+
+    for _ in 0..params.rounds {
+        let opponent_move = (b'A' + rng.gen_range(0..3)) as char;
+        let santa_move = (b'X' + rng.gen_range(0..3)) as char;
+        writeln!(input, "{} {}", opponent_move, santa_move).unwrap();
+    }
+
+    input
+}
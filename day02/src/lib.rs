@@ -0,0 +1,251 @@
+pub mod analysis;
+pub mod encoding;
+pub mod generator;
+pub mod reference;
+pub mod rules;
+pub mod tournament;
+
+use std::str::FromStr;
+
+use aoc_common::{lines, Answer, Error, ParseError, Solution};
+use encoding::Encoding;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    pub fn score(self) -> u32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+
+    /// The shape this one defeats.
+    pub fn beats(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        }
+    }
+
+    /// The shape that defeats this one.
+    pub fn loses_to(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissors,
+            Shape::Scissors => Shape::Rock,
+        }
+    }
+}
+
+/// Reads both columns of the guide: `A` and `X` are rock, `B` and `Y` paper, `C` and `Z` scissors.
+impl FromStr for Shape {
+    type Err = String;
+
+    fn from_str(shape: &str) -> Result<Shape, String> {
+        match shape {
+            "A" | "X" => Ok(Shape::Rock),
+            "B" | "Y" => Ok(Shape::Paper),
+            "C" | "Z" => Ok(Shape::Scissors),
+            _ => Err(String::from("expected a shape, one of A, B, C, X, Y, Z")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    pub fn score(self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// Reads the second column of the guide as part 2 does: `X` to lose, `Y` to draw, `Z` to win.
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(outcome: &str) -> Result<Outcome, String> {
+        match outcome {
+            "X" => Ok(Outcome::Lose),
+            "Y" => Ok(Outcome::Draw),
+            "Z" => Ok(Outcome::Win),
+            _ => Err(String::from("expected an outcome, one of X, Y, Z")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Shape,
+    pub you: Shape,
+}
+
+impl Round {
+    /// The round where you play whatever gives `outcome` against `opponent`.
+    pub fn with_outcome(opponent: Shape, outcome: Outcome) -> Round {
+        let you = match outcome {
+            Outcome::Lose => opponent.beats(),
+            Outcome::Draw => opponent,
+            Outcome::Win => opponent.loses_to(),
+        };
+
+        Round { opponent, you }
+    }
+
+    pub fn outcome(&self) -> Outcome {
+        if self.you.beats() == self.opponent {
+            Outcome::Win
+        } else if self.you == self.opponent {
+            Outcome::Draw
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    pub fn score(&self) -> u32 {
+        self.you.score() + self.outcome().score()
+    }
+}
+
+/// A line of the strategy guide, with its second column read both as your shape (part 1)
+/// and as the outcome of the round (part 2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuideLine {
+    pub opponent: Shape,
+    pub you: Shape,
+    pub outcome: Outcome,
+}
+
+pub fn read_input(filename: &str) -> Result<Vec<GuideLine>, Error> {
+    aoc_common::parse_file(filename, parse_input)
+}
+
+fn parse_game(encoding: &Encoding, line_number: usize, game: &str) -> Result<GuideLine, ParseError> {
+    let (opponent, response) = encoding.parse_line(line_number, game)?;
+
+    Ok(GuideLine { opponent: Shape::ALL[opponent], you: Shape::ALL[response], outcome: Outcome::ALL[response] })
+}
+
+/// Reads the guide with the symbols of `encoding`.
+pub fn parse_with_encoding(input: &str, encoding: &Encoding) -> Result<Vec<GuideLine>, ParseError> {
+    lines(input)
+        .map(|(line_number, game)| parse_game(encoding, line_number, game))
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<GuideLine>, ParseError> {
+    parse_with_encoding(input, &Encoding::default())
+}
+
+fn play_rock_paper_scissor(your_move: Shape, opponent_move: Shape) -> u32 {
+    Round { opponent: opponent_move, you: your_move }.score()
+}
+
+fn play_following_the_elf_strategy(strategy: Outcome, opponent_move: Shape) -> u32 {
+    Round::with_outcome(opponent_move, strategy).score()
+}
+
+pub fn step_1(games: &[GuideLine]) -> u32 {
+    games.iter().map(|game| play_rock_paper_scissor(game.you, game.opponent)).sum()
+}
+
+pub fn step_2(games: &[GuideLine]) -> u32 {
+    games.iter().map(|game| play_following_the_elf_strategy(game.outcome, game.opponent)).sum()
+}
+
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn solve_str(input: &str) -> Result<(u32, u32), ParseError> {
+    let parsed = parse_input(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn default_input(&self) -> &'static str {
+        DEFAULT_INPUT
+    }
+
+    fn step_1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_1(&parse_input(input)?).into())
+    }
+
+    fn step_2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_2(&parse_input(input)?).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    #[test]
+    fn each_shape_beats_the_one_that_loses_to_it() {
+        for shape in Shape::ALL {
+            assert_eq!(shape.beats().loses_to(), shape);
+            assert_ne!(shape.beats(), shape.loses_to());
+        }
+    }
+
+    #[test]
+    fn reads_the_columns_of_the_guide() {
+        assert_eq!("A".parse::<Shape>(), Ok(Shape::Rock));
+        assert_eq!("Z".parse::<Shape>(), Ok(Shape::Scissors));
+        assert_eq!("X".parse::<Outcome>(), Ok(Outcome::Lose));
+        assert!("D".parse::<Shape>().is_err());
+        assert!("A".parse::<Outcome>().is_err());
+    }
+
+    #[test]
+    fn scores_a_round_from_the_two_moves() {
+        assert_eq!(play_rock_paper_scissor(Shape::Paper, Shape::Rock), 8);
+        assert_eq!(play_rock_paper_scissor(Shape::Rock, Shape::Paper), 1);
+        assert_eq!(play_rock_paper_scissor(Shape::Scissors, Shape::Scissors), 6);
+    }
+
+    #[test]
+    fn scores_a_round_from_the_expected_outcome() {
+        assert_eq!(play_following_the_elf_strategy(Outcome::Draw, Shape::Rock), 4);
+        assert_eq!(play_following_the_elf_strategy(Outcome::Lose, Shape::Paper), 1);
+        assert_eq!(play_following_the_elf_strategy(Outcome::Win, Shape::Scissors), 7);
+    }
+
+    #[test]
+    fn step_1_follows_the_guide_as_moves() {
+        assert_eq!(step_1(&read_input(EXAMPLE).unwrap()), 15);
+    }
+
+    #[test]
+    fn step_2_follows_the_guide_as_outcomes() {
+        assert_eq!(step_2(&read_input(EXAMPLE).unwrap()), 12);
+    }
+}
@@ -1,99 +1,105 @@
-use std::{fs, collections::HashMap};
-
-
-fn read_input(filename: &str) -> Vec<(char, char)> {
-    let mut games: Vec<(char, char)> = Vec::new();
-
-    let splitted: Vec<String> = fs::read_to_string(filename)
-        .expect("Cannot read the input")
-        .trim()
-        .split('\n')
-        .map(String::from)
-        .collect();
-    
-    for game in splitted {
-        let opponent_game = game.chars().nth(0).unwrap();
-        let santa_game = game.chars().nth_back(0).unwrap();
-        games.push((opponent_game, santa_game));
-    }    
-    games
+use std::{path::Path, process::ExitCode};
+
+use aoc_common::{Error, InputArgs};
+use clap::Parser;
+use day02::{encoding::Encoding, rules::RuleSet, tournament, Shape};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Day 2: Rock Paper Scissors
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Rule set to score the guide with: `classic`, `rpsls` or the path of a TOML rule set
+    #[arg(long)]
+    rules: Option<String>,
+
+    /// Other meanings for the symbols of the guide, like `X=paper,Y=scissors,Z=rock`
+    #[arg(long, value_name = "SYMBOL=MEANING,...", conflicts_with = "rules")]
+    map: Option<String>,
+
+    /// Compare the two readings of the guide with the best responses to the opponent
+    #[arg(long, conflicts_with = "rules")]
+    analysis: bool,
+
+    /// Number of rounds losing the most points listed by --analysis
+    #[arg(long, default_value_t = 5, value_name = "N")]
+    worst: usize,
+
+    /// Play a round-robin tournament between bots, one of them replaying the guide
+    #[arg(long, conflicts_with_all = ["rules", "analysis"])]
+    tournament: bool,
+
+    /// Number of rounds of each match of the tournament
+    #[arg(long, default_value_t = 1000, value_name = "N")]
+    rounds: usize,
+
+    /// Seed of the tournament; a random one is picked, and printed on stderr, when omitted
+    #[arg(long)]
+    seed: Option<u64>,
 }
 
+fn load_rules(rules: &str) -> Result<RuleSet, String> {
+    match rules {
+        "classic" => Ok(RuleSet::classic()),
+        "rpsls" => Ok(RuleSet::rpsls()),
+        path => RuleSet::load(Path::new(path)),
+    }
+}
+
+fn run(cli: &Cli, rules: Option<&RuleSet>, encoding: &Encoding) -> Result<(), Error> {
+    let load_guide = || cli.input.load(day02::DEFAULT_INPUT, |input| day02::parse_with_encoding(input, encoding));
 
+    if cli.tournament {
+        let games = load_guide()?;
+        let guide_shapes: Vec<Shape> = games.iter().map(|game| game.you).collect();
 
-fn play_rock_paper_scissor(your_move: &char, opponent_move: &char) -> u32 {
-    let shape_score: HashMap<char, u32> = HashMap::from([
-        ('X', 1),
-        ('Y', 2),
-        ('Z', 3),
-    ]);
-    
-    let shape_win: HashMap<char, char> = HashMap::from([
-        ('X', 'C'),
-        ('Y', 'A'),
-        ('Z', 'B'),
-    ]);
-
-    let shape_draw: HashMap<char, char> = HashMap::from([
-        ('X', 'A'),
-        ('Y', 'B'),
-        ('Z', 'C'),
-    ]);
-
-    static WIN_POINTS: u32 = 6;
-    static DRAW_POINTS: u32 = 3;
-    static LOOSE_POINTS: u32 = 0;
-
-    let match_points = shape_score.get(your_move).expect("Unknow move");
-    let opponent_move_for_win = shape_win.get(your_move).expect("Unknow move");
-    let opponent_move_for_draw = shape_draw.get(your_move).expect("Unknow move");
-
-    if opponent_move == opponent_move_for_win {
-        match_points + WIN_POINTS
-    } else if opponent_move == opponent_move_for_draw {
-        match_points + DRAW_POINTS
-    } else {
-        match_points + LOOSE_POINTS
+        let seed = cli.seed.unwrap_or_else(|| {
+            let seed = rand::random();
+            eprintln!("seed: {}", seed);
+            seed
+        });
+        let standings = tournament::tournament(&tournament::standard_bots(&guide_shapes), cli.rounds, &mut ChaCha8Rng::seed_from_u64(seed));
+        print!("{}", tournament::render_leaderboard(&standings));
+
+        return Ok(());
     }
-}
 
-fn play_following_the_elf_strategy(strategy: &char, opponent_move: &char) -> u32 {
-    let shape_win: HashMap<char, char> = HashMap::from([
-        ('C', 'X'),
-        ('A', 'Y'),
-        ('B', 'Z'),
-    ]);
-
-    let shape_draw: HashMap<char, char> = HashMap::from([
-        ('A', 'X'),
-        ('B', 'Y'),
-        ('C', 'Z'),
-    ]);
-
-    let shape_loose: HashMap<char, char> = HashMap::from([
-        ('A', 'Z'),
-        ('B', 'X'),
-        ('C', 'Y'),
-    ]);
-    
-    match strategy {
-        'Z' => play_rock_paper_scissor(&shape_win.get(opponent_move).unwrap(), opponent_move),
-        'Y' => play_rock_paper_scissor(&shape_draw.get(opponent_move).unwrap(), opponent_move),
-        _ => play_rock_paper_scissor(&shape_loose.get(opponent_move).unwrap(), opponent_move)
+    if cli.analysis {
+        let games = load_guide()?;
+        print!("{}", day02::analysis::render(&day02::analysis::analyse(&games), cli.worst));
+
+        return Ok(());
     }
-}
 
-fn step_1(games: &Vec<(char, char)>) -> u32 {
-    games.iter().map(|game| play_rock_paper_scissor(&game.1, &game.0)).sum()
-}
+    let (part_1, part_2) = match rules {
+        Some(rules) => cli.input.load(day02::DEFAULT_INPUT, |input| rules.solve_str(input))?,
+        None => {
+            let games = load_guide()?;
+            (day02::step_1(&games), day02::step_2(&games))
+        }
+    };
 
-fn step_2(games: &Vec<(char, char)>) -> u32 {
-    games.iter().map(|game| play_following_the_elf_strategy(&game.1, &game.0)).sum()
+    println!("Part 1: {}", part_1);
+    println!("Part 2: {}", part_2);
+
+    Ok(())
 }
 
-fn main() {
-    let games = read_input("input.txt");
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let options = cli.rules.as_deref().map(load_rules).transpose()
+        .and_then(|rules| Ok((rules, cli.map.as_deref().map_or(Ok(Encoding::default()), Encoding::with_mapping)?)));
+    let (rules, encoding) = match options {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            return ExitCode::FAILURE;
+        }
+    };
 
-    assert_eq!(step_1(&games), 11841);
-    assert_eq!(step_2(&games), 13022);
+    aoc_common::exit_code(run(&cli, rules.as_ref(), &encoding))
 }
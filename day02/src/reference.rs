@@ -0,0 +1,40 @@
+//! Shapes are numbered 0 (rock), 1 (paper), 2 (scissors): each shape beats the one before it.
+//! Outcomes are numbered 0 (lose), 1 (draw), 2 (win), a third of their score.
+
+use aoc_common::ParseError;
+
+use crate::GuideLine;
+
+fn outcome_score(your_shape: u32, opponent_shape: u32) -> u32 {
+    match (your_shape + 3 - opponent_shape) % 3 {
+        0 => 3,
+        1 => 6,
+        _ => 0,
+    }
+}
+
+pub fn step_1(games: &[GuideLine]) -> u32 {
+    games.iter()
+        .map(|game| {
+            let (opponent_shape, your_shape) = (game.opponent as u32, game.you as u32);
+            your_shape + 1 + outcome_score(your_shape, opponent_shape)
+        })
+        .sum()
+}
+
+pub fn step_2(games: &[GuideLine]) -> u32 {
+    games.iter()
+        .map(|game| {
+            let opponent_shape = game.opponent as u32;
+            let expected_outcome = game.outcome as u32 * 3;
+            let your_shape = (0..3).find(|&candidate| outcome_score(candidate, opponent_shape) == expected_outcome).unwrap();
+            your_shape + 1 + expected_outcome
+        })
+        .sum()
+}
+
+pub fn solve_str(input: &str) -> Result<(u32, u32), ParseError> {
+    let parsed = crate::parse_input(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
@@ -0,0 +1,260 @@
+//! Rule sets described as data, so the guide can be scored for games other than the
+//! classic one. A rule set lists the shapes, with the symbols standing for them in the two
+//! columns of the guide, their points and the shapes each one beats, and the symbols and
+//! points of the three outcomes. See `rules/classic.toml` and `rules/rpsls.toml`.
+//!
+//! Shapes are referred to by their position in `RuleSet::shapes`.
+
+use std::{fs, path::Path};
+
+use aoc_common::{lines, ParseError};
+use serde::Deserialize;
+
+use crate::{encoding::{Encoding, OUTCOME_WORDS}, Outcome};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShapeRule {
+    pub name: String,
+    /// Symbol of the shape in the first column of the guide.
+    pub opponent: String,
+    /// Symbol of the shape in the second column, when it is read as your shape.
+    pub you: String,
+    pub points: u32,
+    /// Names of the shapes this one defeats.
+    #[serde(default)]
+    pub beats: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutcomeRule {
+    /// Symbol of the outcome in the second column, when it is read as the outcome.
+    pub symbol: String,
+    pub points: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutcomeRules {
+    pub lose: OutcomeRule,
+    pub draw: OutcomeRule,
+    pub win: OutcomeRule,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RuleSet {
+    pub name: String,
+    pub shapes: Vec<ShapeRule>,
+    pub outcomes: OutcomeRules,
+    /// `beats[a][b]` tells whether shape `a` defeats shape `b`.
+    #[serde(skip)]
+    beats: Vec<Vec<bool>>,
+}
+
+impl RuleSet {
+    pub fn classic() -> RuleSet {
+        RuleSet::from_toml(include_str!("../rules/classic.toml")).unwrap()
+    }
+
+    pub fn rpsls() -> RuleSet {
+        RuleSet::from_toml(include_str!("../rules/rpsls.toml")).unwrap()
+    }
+
+    pub fn from_toml(config: &str) -> Result<RuleSet, String> {
+        let mut rules: RuleSet = toml::from_str(config).map_err(|err| err.to_string())?;
+        rules.beats = rules.dominance()?;
+
+        Ok(rules)
+    }
+
+    pub fn load(path: &Path) -> Result<RuleSet, String> {
+        let config = fs::read_to_string(path).map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+
+        RuleSet::from_toml(&config).map_err(|err| format!("invalid rule set {}: {}", path.display(), err))
+    }
+
+    /// Checks the rule set describes a game, and builds the table of who beats whom.
+    fn dominance(&self) -> Result<Vec<Vec<bool>>, String> {
+        for (idx, shape) in self.shapes.iter().enumerate() {
+            for other in &self.shapes[..idx] {
+                if other.name == shape.name {
+                    return Err(format!("the shape `{}` is listed twice", shape.name));
+                }
+                if other.opponent == shape.opponent || other.you == shape.you {
+                    return Err(format!("`{}` and `{}` have the same symbol", other.name, shape.name));
+                }
+            }
+        }
+
+        let outcomes = [&self.outcomes.lose, &self.outcomes.draw, &self.outcomes.win];
+        if outcomes[0].symbol == outcomes[1].symbol || outcomes[0].symbol == outcomes[2].symbol || outcomes[1].symbol == outcomes[2].symbol {
+            return Err(String::from("two outcomes have the same symbol"));
+        }
+
+        let mut beats = vec![vec![false; self.shapes.len()]; self.shapes.len()];
+        for (idx, shape) in self.shapes.iter().enumerate() {
+            for beaten in &shape.beats {
+                let beaten_idx = self.shapes.iter().position(|other| &other.name == beaten)
+                    .ok_or_else(|| format!("`{}` beats `{}`, which is not a shape", shape.name, beaten))?;
+                if beaten_idx == idx {
+                    return Err(format!("`{}` cannot beat itself", shape.name));
+                }
+                beats[idx][beaten_idx] = true;
+            }
+        }
+
+        for (first, first_shape) in self.shapes.iter().enumerate() {
+            for (second, second_shape) in self.shapes.iter().enumerate().skip(first + 1) {
+                match (beats[first][second], beats[second][first]) {
+                    (true, true) => return Err(format!("`{}` and `{}` beat each other", first_shape.name, second_shape.name)),
+                    (false, false) => return Err(format!("neither `{}` nor `{}` beats the other", first_shape.name, second_shape.name)),
+                    _ => {}
+                }
+            }
+
+            if !beats[first].contains(&true) {
+                return Err(format!("`{}` does not beat any shape", first_shape.name));
+            }
+            if !beats.iter().any(|row| row[first]) {
+                return Err(format!("no shape beats `{}`", first_shape.name));
+            }
+        }
+
+        Ok(beats)
+    }
+
+    pub fn outcome(&self, opponent: usize, you: usize) -> Outcome {
+        if self.beats[you][opponent] {
+            Outcome::Win
+        } else if you == opponent {
+            Outcome::Draw
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    fn outcome_rule(&self, outcome: Outcome) -> &OutcomeRule {
+        match outcome {
+            Outcome::Lose => &self.outcomes.lose,
+            Outcome::Draw => &self.outcomes.draw,
+            Outcome::Win => &self.outcomes.win,
+        }
+    }
+
+    pub fn score(&self, opponent: usize, you: usize) -> u32 {
+        self.shapes[you].points + self.outcome_rule(self.outcome(opponent, you)).points
+    }
+
+    /// The shape giving `outcome` against `opponent`; when several do, the one scoring the most.
+    pub fn response(&self, opponent: usize, outcome: Outcome) -> usize {
+        (0..self.shapes.len())
+            .filter(|&you| self.outcome(opponent, you) == outcome)
+            .max_by_key(|&you| (self.shapes[you].points, std::cmp::Reverse(you)))
+            .unwrap()
+    }
+
+    /// The symbols of the opponent's column, then the names of the shapes.
+    fn opponent_symbols(&self) -> Vec<(String, usize)> {
+        let symbols = self.shapes.iter().enumerate().map(|(idx, shape)| (shape.opponent.clone(), idx));
+        let names = self.shapes.iter().enumerate().map(|(idx, shape)| (shape.name.clone(), idx));
+
+        symbols.chain(names).collect()
+    }
+
+    /// `symbols` of the second column, then the names of the shapes and outcomes whose symbol
+    /// is among them: as in the puzzle's encoding, `paper` means whatever `Y` means when `Y`
+    /// stands for paper.
+    fn response_symbols(&self, symbols: Vec<(String, usize)>) -> Vec<(String, usize)> {
+        let shape_names = self.shapes.iter().map(|shape| (&shape.you, shape.name.as_str()));
+        let outcome_names = Outcome::ALL.iter().map(|&outcome| &self.outcome_rule(outcome).symbol).zip(OUTCOME_WORDS);
+        let names: Vec<(String, usize)> = shape_names.chain(outcome_names)
+            .filter_map(|(symbol, name)| symbols.iter().find(|(other, _)| other == symbol).map(|&(_, choice)| (String::from(name), choice)))
+            .collect();
+
+        symbols.into_iter().chain(names).collect()
+    }
+
+    /// Reads the guide as part 1 does: the opponent's shape and yours.
+    pub fn parse_rounds(&self, input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+        let symbols = self.shapes.iter().enumerate().map(|(idx, shape)| (shape.you.clone(), idx)).collect();
+        let encoding = Encoding::from_symbols(self.opponent_symbols(), self.response_symbols(symbols));
+
+        lines(input).map(|(line_number, game)| encoding.parse_line(line_number, game)).collect()
+    }
+
+    /// Reads the guide as part 2 does: the opponent's shape and the outcome of the round.
+    pub fn parse_strategies(&self, input: &str) -> Result<Vec<(usize, Outcome)>, ParseError> {
+        let symbols = Outcome::ALL.iter().enumerate().map(|(idx, &outcome)| (self.outcome_rule(outcome).symbol.clone(), idx)).collect();
+        let encoding = Encoding::from_symbols(self.opponent_symbols(), self.response_symbols(symbols));
+
+        lines(input)
+            .map(|(line_number, game)| encoding.parse_line(line_number, game).map(|(opponent, outcome)| (opponent, Outcome::ALL[outcome])))
+            .collect()
+    }
+
+    pub fn step_1(&self, rounds: &[(usize, usize)]) -> u32 {
+        rounds.iter().map(|&(opponent, you)| self.score(opponent, you)).sum()
+    }
+
+    pub fn step_2(&self, strategies: &[(usize, Outcome)]) -> u32 {
+        strategies.iter().map(|&(opponent, outcome)| self.score(opponent, self.response(opponent, outcome))).sum()
+    }
+
+    pub fn solve_str(&self, input: &str) -> Result<(u32, u32), ParseError> {
+        Ok((self.step_1(&self.parse_rounds(input)?), self.step_2(&self.parse_strategies(input)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    #[test]
+    fn classic_rules_solve_the_example() {
+        let example = aoc_common::read_input(EXAMPLE).unwrap();
+
+        assert_eq!(RuleSet::classic().solve_str(&example).unwrap(), (15, 12));
+    }
+
+    #[test]
+    fn spock_vaporizes_rock() {
+        let rules = RuleSet::rpsls();
+
+        assert_eq!(rules.outcome(0, 4), Outcome::Win);
+        assert_eq!(rules.outcome(3, 4), Outcome::Lose);
+        assert_eq!(rules.solve_str("A Z\nD X\n").unwrap(), (11 + 9, 11 + 5));
+    }
+
+    #[test]
+    fn reads_the_names_of_the_shapes_and_outcomes() {
+        let rules = RuleSet::rpsls();
+
+        assert_eq!(rules.parse_rounds("Rock  Spock\nA lizard\n").unwrap(), vec![(0, 4), (0, 3)]);
+        assert_eq!(rules.parse_strategies("spock WIN\n").unwrap(), vec![(4, Outcome::Win)]);
+        assert_eq!(RuleSet::classic().solve_str("Rock Paper\n").unwrap(), RuleSet::classic().solve_str("A Y\n").unwrap());
+
+        // Lizard's symbol is `Y`, the symbol of a draw, and `lose` is `X`, scissors' symbol.
+        assert_eq!(rules.parse_strategies("A lizard\n").unwrap(), vec![(0, Outcome::Draw)]);
+        assert_eq!(rules.parse_rounds("A lose\n").unwrap(), vec![(0, 2)]);
+
+        let err = rules.parse_rounds("A  kick\n").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (4, "kick"));
+    }
+
+    #[test]
+    fn picks_the_best_scoring_shape_for_an_outcome() {
+        let rules = RuleSet::rpsls();
+
+        assert_eq!(rules.response(0, Outcome::Win), 4);
+        assert_eq!(rules.response(0, Outcome::Lose), 3);
+    }
+
+    #[test]
+    fn rejects_inconsistent_rule_sets() {
+        let config = include_str!("../rules/classic.toml");
+
+        assert!(RuleSet::from_toml(&config.replace("beats = [\"paper\"]", "beats = []")).unwrap_err().contains("neither"));
+        assert!(RuleSet::from_toml(&config.replace("beats = [\"paper\"]", "beats = [\"lizard\"]")).unwrap_err().contains("not a shape"));
+        assert!(RuleSet::from_toml(&config.replace("you = \"Z\"", "you = \"X\"")).unwrap_err().contains("same symbol"));
+    }
+}
@@ -0,0 +1,257 @@
+//! Round-robin tournaments between bots, scored like the rounds of the guide.
+
+use rand::{Rng, RngCore};
+
+use crate::{play_rock_paper_scissor, Shape};
+
+pub trait Bot {
+    fn name(&self) -> &str;
+
+    fn play(&mut self, rng: &mut dyn RngCore) -> Shape;
+
+    /// Tells the bot what its opponent played in the last round.
+    fn observe(&mut self, _opponent: Shape) {}
+}
+
+fn random_shape(rng: &mut dyn RngCore) -> Shape {
+    Shape::ALL[rng.gen_range(0..Shape::ALL.len())]
+}
+
+fn position(shape: Shape) -> usize {
+    Shape::ALL.iter().position(|&other| other == shape).unwrap()
+}
+
+pub struct AlwaysRock;
+
+impl Bot for AlwaysRock {
+    fn name(&self) -> &str {
+        "always-rock"
+    }
+
+    fn play(&mut self, _rng: &mut dyn RngCore) -> Shape {
+        Shape::Rock
+    }
+}
+
+pub struct RandomBot;
+
+impl Bot for RandomBot {
+    fn name(&self) -> &str {
+        "random"
+    }
+
+    fn play(&mut self, rng: &mut dyn RngCore) -> Shape {
+        random_shape(rng)
+    }
+}
+
+/// Beats the shape the opponent played the most so far.
+#[derive(Default)]
+pub struct FrequencyCounter {
+    counts: [u32; 3],
+}
+
+impl Bot for FrequencyCounter {
+    fn name(&self) -> &str {
+        "frequency-counter"
+    }
+
+    fn play(&mut self, rng: &mut dyn RngCore) -> Shape {
+        if self.counts == [0; 3] {
+            return random_shape(rng);
+        }
+
+        let favourite = (0..3).max_by_key(|&idx| (self.counts[idx], std::cmp::Reverse(idx))).unwrap();
+        Shape::ALL[favourite].loses_to()
+    }
+
+    fn observe(&mut self, opponent: Shape) {
+        self.counts[position(opponent)] += 1;
+    }
+}
+
+/// Plays the shapes of the guide's second column in a loop.
+pub struct GuideReplay {
+    shapes: Vec<Shape>,
+    next: usize,
+}
+
+impl GuideReplay {
+    pub fn new(shapes: Vec<Shape>) -> GuideReplay {
+        GuideReplay { shapes, next: 0 }
+    }
+}
+
+impl Bot for GuideReplay {
+    fn name(&self) -> &str {
+        "guide"
+    }
+
+    fn play(&mut self, rng: &mut dyn RngCore) -> Shape {
+        if self.shapes.is_empty() {
+            return random_shape(rng);
+        }
+
+        let shape = self.shapes[self.next];
+        self.next = (self.next + 1) % self.shapes.len();
+        shape
+    }
+}
+
+/// Predicts the opponent's next shape from the ones that followed its last shape so far,
+/// a first order Markov chain, and beats the prediction.
+#[derive(Default)]
+pub struct MarkovPredictor {
+    transitions: [[u32; 3]; 3],
+    last: Option<Shape>,
+}
+
+impl Bot for MarkovPredictor {
+    fn name(&self) -> &str {
+        "markov"
+    }
+
+    fn play(&mut self, rng: &mut dyn RngCore) -> Shape {
+        let Some(last) = self.last else {
+            return random_shape(rng);
+        };
+
+        let followers = self.transitions[position(last)];
+        if followers == [0; 3] {
+            return random_shape(rng);
+        }
+
+        let prediction = (0..3).max_by_key(|&idx| (followers[idx], std::cmp::Reverse(idx))).unwrap();
+        Shape::ALL[prediction].loses_to()
+    }
+
+    fn observe(&mut self, opponent: Shape) {
+        if let Some(last) = self.last {
+            self.transitions[position(last)][position(opponent)] += 1;
+        }
+        self.last = Some(opponent);
+    }
+}
+
+/// Builds a fresh bot for every match, so no bot remembers its previous opponents.
+pub type BotFactory<'a> = Box<dyn Fn() -> Box<dyn Bot> + 'a>;
+
+/// The five bots of the tournament, the guide one replaying `guide_shapes`.
+pub fn standard_bots(guide_shapes: &[Shape]) -> Vec<BotFactory<'_>> {
+    vec![
+        Box::new(|| Box::new(AlwaysRock)),
+        Box::new(|| Box::new(RandomBot)),
+        Box::new(|| Box::<FrequencyCounter>::default()),
+        Box::new(move || Box::new(GuideReplay::new(guide_shapes.to_vec()))),
+        Box::new(|| Box::<MarkovPredictor>::default()),
+    ]
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub name: String,
+    pub score: u64,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+}
+
+/// Plays `rounds` rounds between two bots, returning the score of each.
+pub fn play_match(first: &mut dyn Bot, second: &mut dyn Bot, rounds: usize, rng: &mut dyn RngCore) -> (u64, u64) {
+    let (mut first_score, mut second_score) = (0, 0);
+
+    for _ in 0..rounds {
+        let first_shape = first.play(rng);
+        let second_shape = second.play(rng);
+
+        first_score += play_rock_paper_scissor(first_shape, second_shape) as u64;
+        second_score += play_rock_paper_scissor(second_shape, first_shape) as u64;
+
+        first.observe(second_shape);
+        second.observe(first_shape);
+    }
+
+    (first_score, second_score)
+}
+
+/// Every bot plays a match against every other one; the standings are sorted by total score.
+pub fn tournament(bots: &[BotFactory], rounds: usize, rng: &mut dyn RngCore) -> Vec<Standing> {
+    let mut standings: Vec<Standing> = bots.iter()
+        .map(|bot| Standing { name: String::from(bot().name()), score: 0, wins: 0, draws: 0, losses: 0 })
+        .collect();
+
+    for first in 0..bots.len() {
+        for second in first + 1..bots.len() {
+            let (first_score, second_score) = play_match(bots[first]().as_mut(), bots[second]().as_mut(), rounds, rng);
+
+            standings[first].score += first_score;
+            standings[second].score += second_score;
+            match first_score.cmp(&second_score) {
+                std::cmp::Ordering::Greater => {
+                    standings[first].wins += 1;
+                    standings[second].losses += 1;
+                }
+                std::cmp::Ordering::Less => {
+                    standings[first].losses += 1;
+                    standings[second].wins += 1;
+                }
+                std::cmp::Ordering::Equal => {
+                    standings[first].draws += 1;
+                    standings[second].draws += 1;
+                }
+            }
+        }
+    }
+
+    standings.sort_by_key(|standing| std::cmp::Reverse(standing.score));
+    standings
+}
+
+pub fn render_leaderboard(standings: &[Standing]) -> String {
+    let mut leaderboard = format!("{:>4}  {:<18} {:>8} {:>4} {:>4} {:>4}\n", "#", "Bot", "Score", "W", "D", "L");
+    for (rank, standing) in standings.iter().enumerate() {
+        leaderboard.push_str(&format!(
+            "{:>4}  {:<18} {:>8} {:>4} {:>4} {:>4}\n",
+            rank + 1, standing.name, standing.score, standing.wins, standing.draws, standing.losses
+        ));
+    }
+
+    leaderboard
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+
+    #[test]
+    fn frequency_counter_beats_always_rock() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let (rock, counter) = play_match(&mut AlwaysRock, &mut FrequencyCounter::default(), 100, &mut rng);
+
+        assert!(counter >= 99 * 8, "the counter scored {}", counter);
+        assert!(rock <= 99 + 7);
+    }
+
+    #[test]
+    fn markov_predictor_learns_a_cycle() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let mut cycle = GuideReplay::new(Shape::ALL.to_vec());
+        let (_, markov) = play_match(&mut cycle, &mut MarkovPredictor::default(), 300, &mut rng);
+
+        assert!(markov >= 290 * 7, "the predictor scored {}", markov);
+    }
+
+    #[test]
+    fn tournaments_are_reproducible_from_the_seed() {
+        let guide = [Shape::Paper, Shape::Rock, Shape::Scissors];
+        let play = |seed| tournament(&standard_bots(&guide), 200, &mut ChaCha8Rng::seed_from_u64(seed));
+
+        let standings = play(7);
+        assert_eq!(standings, play(7));
+        assert_eq!(standings.len(), 5);
+        assert!(standings.iter().all(|standing| standing.wins + standing.draws + standing.losses == 4));
+    }
+}
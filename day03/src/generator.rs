@@ -0,0 +1,63 @@
+use clap::Args;
+use rand::{seq::SliceRandom, Rng};
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+#[derive(Args, Debug, Clone)]
+pub struct Params {
+    /// Number of groups of three elves
+    #[arg(long, default_value_t = 100)]
+    pub groups: usize,
+
+    /// Most items in a single compartment
+    #[arg(long, default_value_t = 24)]
+    pub max_items: usize,
+}
+
+fn fill_compartment<R: Rng + ?Sized>(rng: &mut R, size: usize, shared: char, own: &[char]) -> Vec<char> {
+    let mut compartment = vec![shared];
+    compartment.extend(own.iter().take(size - 1));
+    while compartment.len() < size {
+        let item = *own.choose(rng).unwrap_or(&shared);
+        compartment.push(item);
+    }
+    compartment.shuffle(rng);
+
+    compartment
+}
+
+/// A rucksack built only from `items`, where exactly one item type is in both compartments.
+fn generate_backpack<R: Rng + ?Sized>(rng: &mut R, params: &Params, mut items: Vec<char>) -> String {
+    items.shuffle(rng);
+    let shared = items.pop().unwrap();
+    let split = rng.gen_range(0..=items.len());
+    let (first_own, second_own) = items.split_at(split);
+
+    let size = rng.gen_range(1..=params.max_items.max(1)).max(first_own.len().max(second_own.len()) + 1);
+    let mut backpack = fill_compartment(rng, size, shared, first_own);
+    backpack.extend(fill_compartment(rng, size, shared, second_own));
+
+    backpack.into_iter().collect()
+}
+
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, params: &Params) -> String {
+    let mut input = String::new();
+
+    for _ in 0..params.groups {
+        let mut items: Vec<char> = ITEMS.chars().collect();
+        items.shuffle(rng);
+        let badge = items.pop().unwrap();
+
+        // Every elf of the group draws its items from a private pool, so the badge is the only shared one.
+        for pool in items.chunks(items.len() / 3) {
+            let own_items = rng.gen_range(0..=pool.len().min(params.max_items.saturating_sub(1)));
+            let mut backpack_items = pool[..own_items].to_vec();
+            backpack_items.push(badge);
+
+            input.push_str(&generate_backpack(rng, params, backpack_items));
+            input.push('\n');
+        }
+    }
+
+    input
+}
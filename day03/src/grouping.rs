@@ -0,0 +1,298 @@
+//! Splits rucksacks listed in no particular order into groups whose elves share exactly one
+//! item type, their badge. Every group that could be formed is listed first, then the search
+//! picks among them a set of groups holding every rucksack once, backtracking on dead ends.
+
+use std::fmt;
+
+use aoc_common::ParseError;
+
+use crate::{items::ItemSet, item_set, Options, Rucksack};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    /// Lines of the rucksacks in the group.
+    pub lines: Vec<usize>,
+    pub badge: char,
+    pub priority: u32,
+}
+
+/// Why the rucksacks cannot be split in groups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoGrouping {
+    UnevenCount { rucksacks: usize, group_size: usize },
+    /// The rucksack at this line shares exactly one item type with no group of the others.
+    Isolated { line: usize },
+    /// Every way of grouping the rucksacks was tried.
+    Exhausted,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupingError {
+    /// A rucksack holds an item without a priority.
+    Parse(ParseError),
+    NoGrouping(NoGrouping),
+}
+
+impl From<ParseError> for GroupingError {
+    fn from(err: ParseError) -> GroupingError {
+        GroupingError::Parse(err)
+    }
+}
+
+impl From<NoGrouping> for GroupingError {
+    fn from(reason: NoGrouping) -> GroupingError {
+        GroupingError::NoGrouping(reason)
+    }
+}
+
+impl fmt::Display for GroupingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GroupingError::Parse(err) => err.fmt(f),
+            GroupingError::NoGrouping(reason) => reason.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for NoGrouping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoGrouping::UnevenCount { rucksacks, group_size } => write!(f, "{} rucksacks cannot be split in groups of {}", rucksacks, group_size),
+            NoGrouping::Isolated { line } => write!(f, "the rucksack at line {} cannot be in any group", line),
+            NoGrouping::Exhausted => write!(f, "no grouping gives every group exactly one badge"),
+        }
+    }
+}
+
+/// An exact cover search: every rucksack must be in exactly one of the chosen candidate groups.
+/// It always goes on with the rucksack left in the fewest candidate groups, and drops the
+/// candidates sharing a rucksack with a chosen group until it backtracks.
+struct Search {
+    candidates: Vec<(Vec<usize>, ItemSet)>,
+    /// Candidate groups of every rucksack.
+    containing: Vec<Vec<usize>>,
+    available: Vec<bool>,
+    /// Number of available candidate groups of every rucksack.
+    options: Vec<usize>,
+    grouped: Vec<bool>,
+    chosen: Vec<usize>,
+}
+
+impl Search {
+    fn new(sets: &[ItemSet], group_size: usize) -> Search {
+        let mut candidates = Vec::new();
+        for (first, &set) in sets.iter().enumerate() {
+            find_candidates(sets, group_size, &mut vec![first], set, &mut candidates);
+        }
+
+        let mut containing = vec![Vec::new(); sets.len()];
+        for (idx, (members, _)) in candidates.iter().enumerate() {
+            for &member in members {
+                containing[member].push(idx);
+            }
+        }
+
+        Search {
+            available: vec![true; candidates.len()],
+            options: containing.iter().map(Vec::len).collect(),
+            grouped: vec![false; sets.len()],
+            chosen: Vec::new(),
+            candidates,
+            containing,
+        }
+    }
+
+    /// Makes `candidate` a group, returning the candidates it rules out.
+    fn choose(&mut self, candidate: usize) -> Vec<usize> {
+        let mut ruled_out = Vec::new();
+        for &member in &self.candidates[candidate].0 {
+            self.grouped[member] = true;
+            for &other in &self.containing[member] {
+                if self.available[other] {
+                    self.available[other] = false;
+                    ruled_out.push(other);
+                    for &other_member in &self.candidates[other].0 {
+                        self.options[other_member] -= 1;
+                    }
+                }
+            }
+        }
+        self.chosen.push(candidate);
+
+        ruled_out
+    }
+
+    fn undo(&mut self, candidate: usize, ruled_out: Vec<usize>) {
+        self.chosen.pop();
+        for other in ruled_out {
+            self.available[other] = true;
+            for &other_member in &self.candidates[other].0 {
+                self.options[other_member] += 1;
+            }
+        }
+        for &member in &self.candidates[candidate].0 {
+            self.grouped[member] = false;
+        }
+    }
+
+    /// Groups the rucksacks left, returning false when they cannot be.
+    fn group_rest(&mut self) -> bool {
+        let Some(rucksack) = (0..self.grouped.len()).filter(|&idx| !self.grouped[idx]).min_by_key(|&idx| self.options[idx]) else {
+            return true;
+        };
+
+        let candidates: Vec<usize> = self.containing[rucksack].iter().copied().filter(|&idx| self.available[idx]).collect();
+        for candidate in candidates {
+            let ruled_out = self.choose(candidate);
+            if self.group_rest() {
+                return true;
+            }
+            self.undo(candidate, ruled_out);
+        }
+
+        false
+    }
+}
+
+/// Adds to `candidates` every group made of `members` and rucksacks after them that shares a
+/// single item type, `shared` being the item types `members` have in common.
+fn find_candidates(sets: &[ItemSet], group_size: usize, members: &mut Vec<usize>, shared: ItemSet, candidates: &mut Vec<(Vec<usize>, ItemSet)>) {
+    if members.len() == group_size {
+        if shared.len() == 1 {
+            candidates.push((members.clone(), shared));
+        }
+        return;
+    }
+
+    for next in members[members.len() - 1] + 1..sets.len() {
+        let still_shared = shared.intersection(sets[next]);
+        if !still_shared.is_empty() {
+            members.push(next);
+            find_candidates(sets, group_size, members, still_shared, candidates);
+            members.pop();
+        }
+    }
+}
+
+/// Splits the rucksacks in groups of `options.group_size` elves sharing exactly one item type.
+/// Inputs often allow several groupings; any one of them is returned, so the priorities of its
+/// badges may not add up to the answer of part 2.
+pub fn find_grouping(backpacks: &[Rucksack], options: &Options) -> Result<Vec<Group>, GroupingError> {
+    let group_size = options.group_size as usize;
+    if !backpacks.len().is_multiple_of(group_size) {
+        return Err(NoGrouping::UnevenCount { rucksacks: backpacks.len(), group_size }.into());
+    }
+
+    let sets = backpacks.iter()
+        .map(|backpack| item_set(backpack, backpack.items, &options.priorities))
+        .collect::<Result<Vec<ItemSet>, ParseError>>()?;
+    let mut search = Search::new(&sets, group_size);
+
+    if let Some(isolated) = search.options.iter().position(|&options| options == 0) {
+        return Err(NoGrouping::Isolated { line: backpacks[isolated].line }.into());
+    }
+
+    if !search.group_rest() {
+        return Err(NoGrouping::Exhausted.into());
+    }
+
+    let mut groups: Vec<Group> = search.chosen.iter()
+        .map(|&candidate| {
+            let (members, badge) = &search.candidates[candidate];
+            let priority = badge.priorities().next().unwrap();
+            Group {
+                lines: members.iter().map(|&member| backpacks[member].line).collect(),
+                badge: options.priorities.item(priority),
+                priority,
+            }
+        })
+        .collect();
+    groups.sort_by_key(|group| group.lines[0]);
+
+    Ok(groups)
+}
+
+pub fn priority_sum(groups: &[Group]) -> u32 {
+    groups.iter().map(|group| group.priority).sum()
+}
+
+pub fn render(groups: &[Group]) -> String {
+    let mut report = String::new();
+    for (idx, group) in groups.iter().enumerate() {
+        let lines: Vec<String> = group.lines.iter().map(|line| line.to_string()).collect();
+        report.push_str(&format!("Group {:>3}: badge {} from lines {}\n", idx + 1, group.badge, lines.join(", ")));
+    }
+    report.push_str(&format!("Sum of the badge priorities: {}\n", priority_sum(groups)));
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{items::PriorityScheme, parse_input};
+
+    fn find_groups_of_three(backpacks: &[Rucksack]) -> Result<Vec<Group>, GroupingError> {
+        find_grouping(backpacks, &Options::default())
+    }
+
+    /// Checks every rucksack is in one group, and each group shares only its badge.
+    fn assert_valid(backpacks: &[Rucksack], groups: &[Group]) {
+        let mut lines: Vec<usize> = groups.iter().flat_map(|group| group.lines.iter().copied()).collect();
+        lines.sort();
+        assert_eq!(lines, backpacks.iter().map(|backpack| backpack.line).collect::<Vec<usize>>());
+
+        for group in groups {
+            let shared = group.lines.iter()
+                .map(|&line| PriorityScheme::aoc().item_set(backpacks.iter().find(|backpack| backpack.line == line).unwrap().items).unwrap())
+                .reduce(ItemSet::intersection)
+                .unwrap();
+            assert_eq!(shared.items(&PriorityScheme::aoc()).collect::<Vec<char>>(), vec![group.badge]);
+        }
+    }
+
+    #[test]
+    fn groups_rucksacks_listed_in_any_order() {
+        let example = aoc_common::read_input(concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt")).unwrap();
+        let mut lines: Vec<&str> = example.lines().collect();
+        lines.swap(1, 4);
+        let shuffled = lines.join("\n");
+        let backpacks = parse_input(&shuffled).unwrap();
+
+        let groups = find_groups_of_three(&backpacks).unwrap();
+
+        assert_valid(&backpacks, &groups);
+        assert_eq!(groups.len(), 2);
+    }
+
+    #[test]
+    fn avoids_groups_that_leave_the_others_without_a_badge() {
+        // Lines 1, 2 and 3 share only `a`, but then 4, 5 and 6 share nothing.
+        let backpacks = parse_input("ap\naq\nar\npq\npr\na\n").unwrap();
+        let groups = find_groups_of_three(&backpacks).unwrap();
+
+        assert_valid(&backpacks, &groups);
+        assert_eq!(groups[0], Group { lines: vec![1, 4, 5], badge: 'p', priority: 16 });
+    }
+
+    #[test]
+    fn proves_no_grouping_exists_when_every_group_overlaps_another() {
+        // The only groups with a badge are 1-2-3, 3-4-5 and 5-6-1, no two of them disjoint.
+        let backpacks = parse_input("ac\na\nab\nb\nbc\nc\n").unwrap();
+
+        assert_eq!(find_groups_of_three(&backpacks), Err(NoGrouping::Exhausted.into()));
+    }
+
+    #[test]
+    fn explains_why_no_grouping_exists() {
+        assert_eq!(find_groups_of_three(&parse_input("ab\nac\n").unwrap()), Err(NoGrouping::UnevenCount { rucksacks: 2, group_size: 3 }.into()));
+        assert_eq!(find_groups_of_three(&parse_input("ab\nac\nad\nz\nac\nab\n").unwrap()), Err(NoGrouping::Isolated { line: 4 }.into()));
+    }
+
+    #[test]
+    fn fails_on_items_without_a_priority() {
+        let backpacks = parse_input("ab\na1\nac\n").unwrap();
+
+        assert!(matches!(find_groups_of_three(&backpacks), Err(GroupingError::Parse(err)) if err.line == 2));
+    }
+}
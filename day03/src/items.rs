@@ -0,0 +1,198 @@
+//! Item types and their priorities. A priority scheme lists the item types from the lowest
+//! priority, 1, to the highest; sets of item types are packed in the bits of a `u64`, the bit
+//! of an item being its priority, so a scheme has at most 63 item types.
+
+use std::str::FromStr;
+
+const AOC_ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriorityScheme {
+    /// Item types by priority, the first one having priority 1.
+    items: Vec<char>,
+    /// Priority of every ASCII character, 0 for the ones that are not item types.
+    ascii: [u8; 128],
+}
+
+impl PriorityScheme {
+    pub const MAX_ITEMS: usize = 63;
+
+    /// The puzzle's scheme: a to z are 1 to 26, A to Z are 27 to 52.
+    pub fn aoc() -> PriorityScheme {
+        PriorityScheme::from_ordering(AOC_ITEMS).unwrap()
+    }
+
+    /// The puzzle's scheme followed by the digits, 0 to 9 being 53 to 62.
+    pub fn with_digits() -> PriorityScheme {
+        PriorityScheme::from_ordering(&format!("{}{}", AOC_ITEMS, DIGITS)).unwrap()
+    }
+
+    /// The item types of `ordering`, from the lowest priority to the highest.
+    pub fn from_ordering(ordering: &str) -> Result<PriorityScheme, String> {
+        let mut scheme = PriorityScheme { items: Vec::new(), ascii: [0; 128] };
+
+        for item in ordering.chars() {
+            if scheme.priority(item).is_some() {
+                return Err(format!("`{}` is listed twice", item));
+            }
+            if scheme.items.len() == PriorityScheme::MAX_ITEMS {
+                return Err(format!("there can be at most {} item types", PriorityScheme::MAX_ITEMS));
+            }
+
+            scheme.items.push(item);
+            if item.is_ascii() {
+                scheme.ascii[item as usize] = scheme.items.len() as u8;
+            }
+        }
+
+        match scheme.items.len() {
+            0 => Err(String::from("there must be at least one item type")),
+            _ => Ok(scheme),
+        }
+    }
+
+    pub fn priority(&self, item: char) -> Option<u32> {
+        if item.is_ascii() {
+            return match self.ascii[item as usize] {
+                0 => None,
+                priority => Some(priority as u32),
+            };
+        }
+
+        self.items.iter().position(|&other| other == item).map(|idx| idx as u32 + 1)
+    }
+
+    /// The item type with the given priority.
+    pub fn item(&self, priority: u32) -> char {
+        self.items[priority as usize - 1]
+    }
+
+    /// The set of the item types in `items`, or the first character that is not an item type.
+    pub fn item_set(&self, items: &str) -> Result<ItemSet, char> {
+        let mut set = ItemSet::EMPTY;
+        for item in items.chars() {
+            set.insert(self.priority(item).ok_or(item)?);
+        }
+
+        Ok(set)
+    }
+}
+
+impl Default for PriorityScheme {
+    fn default() -> PriorityScheme {
+        PriorityScheme::aoc()
+    }
+}
+
+impl FromStr for PriorityScheme {
+    type Err = String;
+
+    /// `aoc`, `digits`, or the item types ordered from the lowest priority.
+    fn from_str(scheme: &str) -> Result<PriorityScheme, String> {
+        match scheme {
+            "aoc" => Ok(PriorityScheme::aoc()),
+            "digits" => Ok(PriorityScheme::with_digits()),
+            ordering => PriorityScheme::from_ordering(ordering),
+        }
+    }
+}
+
+/// A set of item types, by priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    pub fn insert(&mut self, priority: u32) {
+        self.0 |= 1 << priority;
+    }
+
+    pub fn contains(self, priority: u32) -> bool {
+        self.0 & (1 << priority) != 0
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Priorities of the item types in the set, lowest first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let priority = bits.trailing_zeros();
+            bits &= bits - 1;
+            Some(priority)
+        })
+    }
+
+    /// Item types in the set, by priority.
+    pub fn items(self, scheme: &PriorityScheme) -> impl Iterator<Item = char> + '_ {
+        self.priorities().map(|priority| scheme.item(priority))
+    }
+
+    pub fn priority_sum(self) -> u32 {
+        self.priorities().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn looks_up_the_priority_of_items() {
+        let aoc = PriorityScheme::aoc();
+
+        assert_eq!(aoc.priority('a'), Some(1));
+        assert_eq!(aoc.priority('z'), Some(26));
+        assert_eq!(aoc.priority('A'), Some(27));
+        assert_eq!(aoc.priority('Z'), Some(52));
+        assert_eq!(aoc.priority('1'), None);
+        assert_eq!(aoc.priority('é'), None);
+        assert_eq!((1..=52).map(|priority| aoc.item(priority)).collect::<String>(), AOC_ITEMS);
+    }
+
+    #[test]
+    fn extends_the_priorities_to_digits_and_other_orderings() {
+        let digits = PriorityScheme::with_digits();
+        assert_eq!((digits.priority('Z'), digits.priority('0'), digits.priority('9')), (Some(52), Some(53), Some(62)));
+
+        let custom: PriorityScheme = "zé0".parse().unwrap();
+        assert_eq!((custom.priority('z'), custom.priority('é'), custom.priority('0'), custom.priority('a')), (Some(1), Some(2), Some(3), None));
+
+        assert_eq!(PriorityScheme::from_ordering("abca").unwrap_err(), "`a` is listed twice");
+        assert!(PriorityScheme::from_ordering("").is_err());
+        assert!(PriorityScheme::from_ordering(&format!("{}{}é", AOC_ITEMS, DIGITS)).is_ok());
+        assert!(PriorityScheme::from_ordering(&format!("{}{}éè", AOC_ITEMS, DIGITS)).is_err());
+    }
+
+    #[test]
+    fn combines_sets_of_items() {
+        let aoc = PriorityScheme::aoc();
+        let first = aoc.item_set("vJrwpWtwJgWr").unwrap();
+        let second = aoc.item_set("hcsFMMfFFhFp").unwrap();
+
+        assert_eq!(first.intersection(second).items(&aoc).collect::<String>(), "p");
+        assert_eq!(first.union(second).len(), 14);
+        assert!(first.contains(aoc.priority('W').unwrap()) && !first.contains(aoc.priority('h').unwrap()));
+        assert_eq!(aoc.item_set("aZb").unwrap().priority_sum(), 1 + 52 + 2);
+        assert_eq!(aoc.item_set("ab?c"), Err('?'));
+    }
+}
@@ -0,0 +1,303 @@
+pub mod generator;
+pub mod grouping;
+pub mod items;
+pub mod reference;
+
+use aoc_common::{lines, Answer, ParseError, Solution};
+use clap::{Args, ValueEnum};
+
+use items::{ItemSet, PriorityScheme};
+
+/// The items of a rucksack, with the line of the input listing them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack<'a> {
+    pub line: usize,
+    pub items: &'a str,
+}
+
+/// What to do with a rucksack whose compartments share more than one item type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Policy {
+    /// Reject the rucksack
+    #[default]
+    Error,
+    /// Count the shared item listed first in the rucksack
+    TakeFirst,
+    /// Count every shared item
+    SumAll,
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Rucksack<'_>>, ParseError> {
+    Ok(lines(input).map(|(line, items)| Rucksack { line, items }).collect())
+}
+
+/// How the rucksacks are laid out and read.
+#[derive(Args, Debug, Clone)]
+pub struct Options {
+    /// Number of equal compartments in every rucksack
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u16).range(2..))]
+    pub compartments: u16,
+
+    /// Number of elves in every group
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u16).range(1..))]
+    pub group_size: u16,
+
+    /// How part 1 counts a rucksack whose compartments share several item types
+    #[arg(long, value_enum, default_value_t = Policy::Error)]
+    pub policy: Policy,
+
+    /// Priorities of the item types: `aoc`, `digits` to add 0-9 after Z, or the item types from the lowest priority
+    #[arg(long, value_name = "SCHEME", default_value = "aoc")]
+    pub priorities: PriorityScheme,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options { compartments: 2, group_size: 3, policy: Policy::Error, priorities: PriorityScheme::aoc() }
+    }
+}
+
+/// The compartments of a rucksack, which must hold the same number of items.
+fn compartments<'a>(rucksack: &Rucksack<'a>, count: usize) -> Result<impl Iterator<Item = &'a str>, ParseError> {
+    let items = rucksack.items.chars().count();
+    if !items.is_multiple_of(count) {
+        return Err(ParseError::whole_line(
+            rucksack.line, rucksack.items, format!("{} items cannot be split in {} equal compartments", items, count),
+        ));
+    }
+
+    let mut rest = rucksack.items;
+    Ok((0..count).map(move |_| {
+        let end = rest.char_indices().nth(items / count).map_or(rest.len(), |(idx, _)| idx);
+        let (compartment, others) = rest.split_at(end);
+        rest = others;
+        compartment
+    }))
+}
+
+/// Item types of `items`, a part of `rucksack`.
+pub(crate) fn item_set(rucksack: &Rucksack, items: &str, scheme: &PriorityScheme) -> Result<ItemSet, ParseError> {
+    scheme.item_set(items).map_err(|item| {
+        let start = items.find(item).unwrap();
+        ParseError::in_line(rucksack.line, rucksack.items, &items[start..start + item.len_utf8()], format!("`{}` has no priority", item))
+    })
+}
+
+fn list_items(items: ItemSet, scheme: &PriorityScheme) -> String {
+    items.items(scheme).map(|item| format!("`{}`", item)).collect::<Vec<String>>().join(", ")
+}
+
+/// The misplaced item types of a rucksack that count towards part 1.
+fn find_overlap_item_in_backpack(rucksack: &Rucksack, options: &Options) -> Result<ItemSet, ParseError> {
+    let scheme = &options.priorities;
+    let mut compartments = compartments(rucksack, options.compartments as usize)?;
+    let first_compartment = compartments.next().unwrap();
+
+    let mut items_in_all = item_set(rucksack, first_compartment, scheme)?;
+    for compartment in compartments {
+        items_in_all = items_in_all.intersection(item_set(rucksack, compartment, scheme)?);
+    }
+
+    match (items_in_all.len(), options.policy) {
+        (0, _) => Err(ParseError::whole_line(rucksack.line, rucksack.items, "the compartments do not share any item")),
+        (1, _) | (_, Policy::SumAll) => Ok(items_in_all),
+        (_, Policy::TakeFirst) => {
+            let mut first = ItemSet::EMPTY;
+            first.insert(first_compartment.chars().filter_map(|item| scheme.priority(item)).find(|&priority| items_in_all.contains(priority)).unwrap());
+            Ok(first)
+        }
+        (_, Policy::Error) => Err(ParseError::whole_line(
+            rucksack.line, rucksack.items, format!("the compartments share several items: {}", list_items(items_in_all, scheme)),
+        )),
+    }
+}
+
+/// Every rucksack whose compartments cannot be told apart or do not share exactly one item.
+pub fn diagnose(backpacks: &[Rucksack], options: &Options) -> Vec<ParseError> {
+    let options = Options { policy: Policy::Error, ..options.clone() };
+
+    backpacks.iter()
+        .filter_map(|backpack| find_overlap_item_in_backpack(backpack, &options).err())
+        .collect()
+}
+
+pub fn step_1_with(backpacks: &[Rucksack], options: &Options) -> Result<u32, ParseError> {
+    let mut total = 0;
+    for backpack in backpacks {
+        total += find_overlap_item_in_backpack(backpack, options)?.priority_sum();
+    }
+
+    Ok(total)
+}
+
+pub fn step_1(backpacks: &[Rucksack]) -> Result<u32, ParseError> {
+    step_1_with(backpacks, &Options::default())
+}
+
+/// The badge of a group, the only item type all its elves carry.
+fn find_common_item_in_backpacks(backpacks: &[Rucksack], scheme: &PriorityScheme) -> Result<ItemSet, ParseError> {
+    let first = &backpacks[0];
+
+    let mut common = item_set(first, first.items, scheme)?;
+    for backpack in &backpacks[1..] {
+        common = common.intersection(item_set(backpack, backpack.items, scheme)?);
+    }
+
+    match common.len() {
+        1 => Ok(common),
+        0 => Err(ParseError::whole_line(first.line, first.items, "the group starting here does not share any item")),
+        _ => Err(ParseError::whole_line(
+            first.line, first.items, format!("the group starting here shares several items: {}", list_items(common, scheme)),
+        )),
+    }
+}
+
+pub fn step_2_with(backpacks: &[Rucksack], options: &Options) -> Result<u32, ParseError> {
+    let group_size = options.group_size as usize;
+    if !backpacks.len().is_multiple_of(group_size) {
+        let last_group = &backpacks[backpacks.len() / group_size * group_size..];
+        return Err(ParseError::whole_line(
+            last_group[0].line, last_group[0].items, format!("the last group has {} elves instead of {}", last_group.len(), group_size),
+        ));
+    }
+
+    let mut total = 0;
+    for group in backpacks.chunks(group_size) {
+        total += find_common_item_in_backpacks(group, &options.priorities)?.priority_sum();
+    }
+
+    Ok(total)
+}
+
+pub fn step_2(backpacks: &[Rucksack]) -> Result<u32, ParseError> {
+    step_2_with(backpacks, &Options::default())
+}
+
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn solve_str(input: &str) -> Result<(u32, u32), ParseError> {
+    let parsed = parse_input(input)?;
+
+    Ok((step_1(&parsed)?, step_2(&parsed)?))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn default_input(&self) -> &'static str {
+        DEFAULT_INPUT
+    }
+
+    fn step_1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_1(&parse_input(input)?)?.into())
+    }
+
+    fn step_2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_2(&parse_input(input)?)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::read_input;
+
+    use super::*;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    fn items(set: ItemSet) -> String {
+        set.items(&PriorityScheme::aoc()).collect()
+    }
+
+    #[test]
+    fn finds_the_item_in_both_compartments() {
+        assert_eq!(items(find_overlap_item_in_backpack(&Rucksack { line: 1, items: "vJrwpWtwJgWrhcsFMMfFFhFp" }, &Options::default()).unwrap()), "p");
+        assert_eq!(items(find_overlap_item_in_backpack(&Rucksack { line: 2, items: "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL" }, &Options::default()).unwrap()), "L");
+    }
+
+    #[test]
+    fn applies_the_policy_to_compartments_sharing_several_items() {
+        let backpack = Rucksack { line: 4, items: "bcaCab" };
+
+        let err = find_overlap_item_in_backpack(&backpack, &Options::default()).unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (4, "the compartments share several items: `a`, `b`"));
+        assert_eq!(items(find_overlap_item_in_backpack(&backpack, &Options { policy: Policy::TakeFirst, ..Options::default() }).unwrap()), "b");
+        assert_eq!(step_1_with(&[backpack], &Options { policy: Policy::SumAll, ..Options::default() }).unwrap(), 3);
+    }
+
+    #[test]
+    fn rejects_items_without_a_priority() {
+        let backpacks = parse_input("vJrwpWtwJgWrhcsFMMfFFhFp\nab11cd\n").unwrap();
+
+        let err = step_1(&backpacks).unwrap_err();
+        assert_eq!((err.line, err.column, err.message.as_str()), (2, 3, "`1` has no priority"));
+        let digits = Options { priorities: PriorityScheme::with_digits(), ..Options::default() };
+        assert_eq!(step_1_with(&backpacks, &digits).unwrap(), 16 + 54);
+    }
+
+    #[test]
+    fn splits_compartments_by_characters_with_custom_priorities() {
+        let backpacks = parse_input("éaèé\n").unwrap();
+        let options = Options { priorities: "aéè".parse().unwrap(), ..Options::default() };
+
+        assert_eq!(step_1_with(&backpacks, &options).unwrap(), 2);
+    }
+
+    #[test]
+    fn diagnoses_every_offending_rucksack() {
+        let backpacks = parse_input("abcab\nvJrwpWtwJgWrhcsFMMfFFhFp\nabcd\nabba\n").unwrap();
+        let diagnostics: Vec<(usize, String)> = diagnose(&backpacks, &Options::default()).into_iter().map(|err| (err.line, err.message)).collect();
+
+        assert_eq!(diagnostics, vec![
+            (1, String::from("5 items cannot be split in 2 equal compartments")),
+            (3, String::from("the compartments do not share any item")),
+            (4, String::from("the compartments share several items: `a`, `b`")),
+        ]);
+    }
+
+    #[test]
+    fn finds_the_badge_of_each_group() {
+        let example = read_input(EXAMPLE).unwrap();
+        let backpacks = parse_input(&example).unwrap();
+
+        assert_eq!(items(find_common_item_in_backpacks(&backpacks[..3], &PriorityScheme::aoc()).unwrap()), "r");
+        assert_eq!(items(find_common_item_in_backpacks(&backpacks[3..], &PriorityScheme::aoc()).unwrap()), "Z");
+    }
+
+    #[test]
+    fn step_1_sums_the_priorities_of_misplaced_items() {
+        assert_eq!(step_1(&parse_input(&read_input(EXAMPLE).unwrap()).unwrap()).unwrap(), 157);
+    }
+
+    #[test]
+    fn step_2_sums_the_priorities_of_the_badges() {
+        assert_eq!(step_2(&parse_input(&read_input(EXAMPLE).unwrap()).unwrap()).unwrap(), 70);
+    }
+
+    #[test]
+    fn splits_rucksacks_in_any_number_of_compartments() {
+        let backpacks = parse_input("abcAbcaBc\nxyXYxy\n").unwrap();
+        let options = Options { compartments: 3, group_size: 2, ..Options::default() };
+
+        assert_eq!(items(find_overlap_item_in_backpack(&backpacks[0], &options).unwrap()), "c");
+        let err = step_1_with(&backpacks, &options).unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (2, "the compartments do not share any item"));
+        assert_eq!(step_2_with(&backpacks, &options).unwrap_err().message, "the group starting here does not share any item");
+    }
+
+    #[test]
+    fn rejects_rucksacks_and_groups_that_do_not_divide_evenly() {
+        let example = read_input(EXAMPLE).unwrap();
+        let backpacks = parse_input(&example).unwrap();
+        let options = Options { compartments: 5, group_size: 4, ..Options::default() };
+
+        let err = step_1_with(&backpacks, &options).unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (1, "24 items cannot be split in 5 equal compartments"));
+        let err = step_2_with(&backpacks, &options).unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (5, "the last group has 2 elves instead of 4"));
+    }
+}
@@ -1,85 +1,67 @@
-use std::{fs, collections::HashSet};
+use std::{path::Path, process::ExitCode};
 
-fn read_input(filename: &str) -> Vec<String> {
-    fs::read_to_string(filename)
-        .expect("Cannot read the input")
-        .trim()
-        .split('\n')
-        .map(String::from)
-        .collect()
-}
+use aoc_common::{input_name, Error, InputArgs};
+use clap::Parser;
+use day03::{grouping::GroupingError, Options};
 
-fn find_overlap_item_in_backpack(first_half: &String, second_half: &String) -> char {
-    let items_in_first_half: HashSet<char> = first_half.chars().collect();
-    let items_in_second_half: HashSet<char> = second_half.chars().collect();
+/// Day 3: Rucksack Reorganization
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
 
-    let mut items_in_both: Vec<&char>  = items_in_first_half.intersection(&items_in_second_half).collect();
+    #[command(flatten)]
+    options: Options,
 
-    if items_in_both.len() > 1 {
-        panic!("I expect only an element in common between the two halfs");
-    }
+    /// List every rucksack that cannot be split in its compartments or does not have exactly one misplaced item, instead of the two parts
+    #[arg(long)]
+    check: bool,
 
-    items_in_both.pop().expect("No element in common between the two half").clone()
+    /// Group the rucksacks so that every group shares exactly one badge, whatever their order, instead of the two parts
+    #[arg(long)]
+    find_groups: bool,
 }
 
-fn calculate_priority(overlap_item: char) -> u32 {
-    let overlap_code = overlap_item as u32;
-    let lower_a_code = 'a' as u32;
-    let upper_a_code = 'A' as u32;
-
-    if overlap_item.is_lowercase() {
-        overlap_code - lower_a_code + 1
-    } else {
-        overlap_code - upper_a_code + 27
-    }
-}
+fn run(cli: &Cli) -> Result<(), Error> {
+    if cli.check {
+        let diagnostics = cli.input.load(day03::DEFAULT_INPUT, |input| Ok(day03::diagnose(&day03::parse_input(input)?, &cli.options)))?;
 
-fn step_1(backpacks: &Vec<String>) -> u32 {
-    backpacks.iter()
-        .map(|backpack| -> (String, String) {
-            let half = backpack.len() / 2;
-            let first_half = String::from(&backpack[..half]);
-            let second_half = String::from(&backpack[half..]);
+        let filename = input_name(Path::new(cli.input.filename(day03::DEFAULT_INPUT)));
+        for diagnostic in &diagnostics {
+            eprintln!("{}", diagnostic.clone().with_file(filename).render());
+        }
+        println!("{} rucksack(s) to fix", diagnostics.len());
 
-            (first_half, second_half)
-        })
-        .map(|backpack_content| find_overlap_item_in_backpack(&backpack_content.0, &backpack_content.1))
-        .map(calculate_priority)
-        .sum()
-}
+        return Ok(());
+    }
 
-fn find_common_item_in_backpacks(backpacks: &[String]) -> char {
-    let mut backpacks_items: Vec<HashSet<char>> = backpacks
-        .to_vec()
-        .iter()
-        .map(|backpack| backpack.chars().collect::<HashSet<char>>())
-        .collect();
+    if cli.find_groups {
+        let grouping = cli.input.load(day03::DEFAULT_INPUT, |input| {
+            match day03::grouping::find_grouping(&day03::parse_input(input)?, &cli.options) {
+                Err(GroupingError::Parse(err)) => Err(err),
+                grouping => Ok(grouping),
+            }
+        })?;
 
-    let (intersection, others) = backpacks_items.split_at_mut(1);
-    let intersection = &mut intersection[0];
+        match grouping {
+            Ok(groups) => print!("{}", day03::grouping::render(&groups)),
+            Err(reason) => println!("No grouping exists: {}", reason),
+        }
 
-    for backpack in others {
-       intersection.retain(|item| backpack.contains(item));
+        return Ok(());
     }
 
-    if intersection.len() > 1 {
-        panic!("I expect only an element in common in a single group");
-    }
+    let (part_1, part_2) = cli.input.load(day03::DEFAULT_INPUT, |input| {
+        let backpacks = day03::parse_input(input)?;
+        Ok((day03::step_1_with(&backpacks, &cli.options)?, day03::step_2_with(&backpacks, &cli.options)?))
+    })?;
 
-    intersection.iter().next().unwrap().clone()
-}
+    println!("Part 1: {}", part_1);
+    println!("Part 2: {}", part_2);
 
-fn step_2(backpacks: &Vec<String>) -> u32 {
-    backpacks.chunks(3)
-        .map(find_common_item_in_backpacks)
-        .map(calculate_priority)
-        .sum()
+    Ok(())
 }
 
-fn main() {
-    let input = read_input("input.txt");
-
-    assert_eq!(step_1(&input), 7795);
-    assert_eq!(step_2(&input), 2703);
+fn main() -> ExitCode {
+    aoc_common::exit_code(run(&Cli::parse()))
 }
-
@@ -0,0 +1,42 @@
+use aoc_common::ParseError;
+
+use crate::Rucksack;
+
+const ITEMS_BY_PRIORITY: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn priority(item: char) -> u32 {
+    ITEMS_BY_PRIORITY.find(item).unwrap() as u32 + 1
+}
+
+/// Sum of the priorities of the items found in every rucksack, each item counted once.
+fn shared_items_priority(rucksacks: &[&str]) -> u32 {
+    ITEMS_BY_PRIORITY.chars()
+        .filter(|&item| rucksacks.iter().all(|rucksack| rucksack.contains(item)))
+        .map(priority)
+        .sum()
+}
+
+pub fn step_1(backpacks: &[Rucksack]) -> u32 {
+    backpacks.iter()
+        .map(|backpack| {
+            let (first_half, second_half) = backpack.items.split_at(backpack.items.len() / 2);
+            shared_items_priority(&[first_half, second_half])
+        })
+        .sum()
+}
+
+pub fn step_2(backpacks: &[Rucksack]) -> u32 {
+    let mut total = 0;
+    for group_start in (0..backpacks.len()).step_by(3) {
+        let group: Vec<&str> = backpacks[group_start..(group_start + 3).min(backpacks.len())].iter().map(|backpack| backpack.items).collect();
+        total += shared_items_priority(&group);
+    }
+
+    total
+}
+
+pub fn solve_str(input: &str) -> Result<(u32, u32), ParseError> {
+    let parsed = crate::parse_input(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
@@ -0,0 +1,35 @@
+use std::fmt::Write;
+
+use clap::Args;
+use rand::Rng;
+
+#[derive(Args, Debug, Clone)]
+pub struct Params {
+    /// Number of pairs of elves
+    #[arg(long, default_value_t = 1000)]
+    pub pairs: usize,
+
+    /// Highest section ID
+    #[arg(long, default_value_t = 99)]
+    pub sections: u32,
+}
+
+fn section_range<R: Rng + ?Sized>(rng: &mut R, sections: u32) -> (u32, u32) {
+    let first_section = rng.gen_range(1..=sections);
+    let last_section = rng.gen_range(first_section..=sections);
+
+    (first_section, last_section)
+}
+
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, params: &Params) -> String {
+    let sections = params.sections.max(1);
+    let mut input = String::new();
+
+    for _ in 0..params.pairs {
+        let first = section_range(rng, sections);
+        let second = section_range(rng, sections);
+        writeln!(input, "{}-{},{}-{}", first.0, first.1, second.0, second.1).unwrap();
+    }
+
+    input
+}
@@ -0,0 +1,132 @@
+pub mod generator;
+pub mod reference;
+
+use aoc_common::{lines, Answer, Error, ParseError, Solution};
+
+pub type ElfSections = (u32, u32);
+pub type InputRow = (ElfSections, ElfSections);
+pub type InputType = Vec<InputRow>;
+
+
+pub fn read_input(filename: &str) -> Result<InputType, Error> {
+    aoc_common::parse_file(filename, parse_input)
+}
+
+pub fn parse_input(input: &str) -> Result<InputType, ParseError> {
+    fn build_section_range(line_number: usize, row: &str, section_range: &str) -> Result<ElfSections, ParseError> {
+        let (first_section, last_section) = section_range.split_once('-')
+            .ok_or_else(|| ParseError::in_line(line_number, row, section_range, "expected a section range like `2-4`"))?;
+
+        let parse_section = |section: &str| section.parse::<u32>()
+            .map_err(|_| ParseError::in_line(line_number, row, section, "expected a section number"));
+
+        Ok((parse_section(first_section)?, parse_section(last_section)?))
+    }
+
+    fn build_pair_sections(line_number: usize, pair_section: &str) -> Result<InputRow, ParseError> {
+        let (first_range, second_range) = pair_section.split_once(',')
+            .ok_or_else(|| ParseError::whole_line(line_number, pair_section, "expected two comma separated section ranges"))?;
+
+        let first_sections = build_section_range(line_number, pair_section, first_range)?;
+        let second_sections = build_section_range(line_number, pair_section, second_range)?;
+
+        Ok((first_sections, second_sections))
+    }
+
+    lines(input)
+        .map(|(line_number, pair_section)| build_pair_sections(line_number, pair_section))
+        .collect()
+}
+
+fn is_a_section_in_the_other(first_sections: &ElfSections, second_sections: &ElfSections) -> bool {
+    let first_in_second = (first_sections.0 >= second_sections.0) && (first_sections.1 <= second_sections.1);
+    let second_in_first = (first_sections.0 <= second_sections.0) && (first_sections.1 >= second_sections.1);
+
+    first_in_second || second_in_first
+}
+
+pub fn step_1(elf_sections_pairs: &InputType) -> u32 {
+    elf_sections_pairs.iter()
+        .map(|sections_pair| is_a_section_in_the_other(&sections_pair.0, &sections_pair.1))
+        .filter(|is_first_in_second| *is_first_in_second)
+        .count() as u32
+}
+
+fn are_sections_overlap(first_sections: &ElfSections, second_sections: &ElfSections) -> bool {
+    let is_first_extreme_in_second = first_sections.0 >= second_sections.0 && first_sections.0 <= second_sections.1;
+    let is_second_extreme_in_second = first_sections.1 >= second_sections.0 && first_sections.1 <= second_sections.1;
+
+    let is_first_extreme_in_first = second_sections.0 >= first_sections.0 && second_sections.0 <= first_sections.1;
+    let is_second_extreme_in_first = second_sections.1 >= first_sections.0 && second_sections.1 <= first_sections.1;
+
+    is_first_extreme_in_second || is_second_extreme_in_second || is_first_extreme_in_first || is_second_extreme_in_first
+}
+
+pub fn step_2(elf_sections_pairs: &InputType) -> u32 {
+    elf_sections_pairs.iter()
+        .map(|sections_pair| are_sections_overlap(&sections_pair.0, &sections_pair.1))
+        .filter(|is_first_in_second| *is_first_in_second)
+        .count() as u32
+}
+
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn solve_str(input: &str) -> Result<(u32, u32), ParseError> {
+    let parsed = parse_input(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn default_input(&self) -> &'static str {
+        DEFAULT_INPUT
+    }
+
+    fn step_1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_1(&parse_input(input)?).into())
+    }
+
+    fn step_2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_2(&parse_input(input)?).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    #[test]
+    fn detects_a_range_fully_contained_in_the_other() {
+        assert!(is_a_section_in_the_other(&(2, 8), &(3, 7)));
+        assert!(is_a_section_in_the_other(&(6, 6), &(4, 6)));
+        assert!(!is_a_section_in_the_other(&(2, 4), &(6, 8)));
+        assert!(!is_a_section_in_the_other(&(5, 7), &(7, 9)));
+    }
+
+    #[test]
+    fn detects_overlapping_ranges() {
+        assert!(are_sections_overlap(&(5, 7), &(7, 9)));
+        assert!(are_sections_overlap(&(2, 8), &(3, 7)));
+        assert!(are_sections_overlap(&(2, 6), &(4, 8)));
+        assert!(!are_sections_overlap(&(2, 4), &(6, 8)));
+        assert!(!are_sections_overlap(&(2, 3), &(4, 5)));
+    }
+
+    #[test]
+    fn step_1_counts_fully_contained_pairs() {
+        assert_eq!(step_1(&read_input(EXAMPLE).unwrap()), 2);
+    }
+
+    #[test]
+    fn step_2_counts_overlapping_pairs() {
+        assert_eq!(step_2(&read_input(EXAMPLE).unwrap()), 4);
+    }
+}
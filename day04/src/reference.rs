@@ -0,0 +1,30 @@
+//! Every section of the two ranges is enumerated instead of comparing the bounds.
+
+use aoc_common::ParseError;
+
+use crate::{ElfSections, InputType};
+
+fn sections(range: &ElfSections) -> Vec<u32> {
+    (range.0..=range.1).collect()
+}
+
+pub fn step_1(elf_sections_pairs: &InputType) -> u32 {
+    elf_sections_pairs.iter()
+        .filter(|(first, second)| {
+            let (first, second) = (sections(first), sections(second));
+            first.iter().all(|section| second.contains(section)) || second.iter().all(|section| first.contains(section))
+        })
+        .count() as u32
+}
+
+pub fn step_2(elf_sections_pairs: &InputType) -> u32 {
+    elf_sections_pairs.iter()
+        .filter(|(first, second)| sections(first).iter().any(|section| sections(second).contains(section)))
+        .count() as u32
+}
+
+pub fn solve_str(input: &str) -> Result<(u32, u32), ParseError> {
+    let parsed = crate::parse_input(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
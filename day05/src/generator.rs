@@ -0,0 +1,75 @@
+use std::fmt::Write;
+
+use clap::Args;
+use rand::Rng;
+
+#[derive(Args, Debug, Clone)]
+pub struct Params {
+    /// Number of stacks
+    #[arg(long, default_value_t = 9)]
+    pub stacks: usize,
+
+    /// Highest initial stack
+    #[arg(long, default_value_t = 8)]
+    pub height: usize,
+
+    /// Number of movements
+    #[arg(long, default_value_t = 500)]
+    pub moves: usize,
+}
+
+fn render_stacks(stacks: &[Vec<char>]) -> String {
+    let mut drawing = String::new();
+    let highest = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+    for level in (0..highest).rev() {
+        let row: Vec<String> = stacks.iter()
+            .map(|stack| match stack.get(level) {
+                Some(crate_name) => format!("[{}]", crate_name),
+                None => String::from("   "),
+            })
+            .collect();
+        writeln!(drawing, "{}", row.join(" ")).unwrap();
+    }
+
+    let numbers: Vec<String> = (1..=stacks.len()).map(|number| format!(" {} ", number)).collect();
+    writeln!(drawing, "{}", numbers.join(" ")).unwrap();
+
+    drawing
+}
+
+/// Movements never empty a stack, so every stack still has a crate on top at the end.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, params: &Params) -> String {
+    let num_of_stacks = params.stacks.max(1);
+    let stacks: Vec<Vec<char>> = (0..num_of_stacks)
+        .map(|_| {
+            (0..rng.gen_range(1..=params.height.max(1)))
+                .map(|_| (b'A' + rng.gen_range(0..26)) as char)
+                .collect()
+        })
+        .collect();
+
+    let mut input = render_stacks(&stacks);
+    input.push('\n');
+
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    for _ in 0..params.moves {
+        let origins: Vec<usize> = (0..num_of_stacks).filter(|&stack| heights[stack] > 1).collect();
+        if origins.is_empty() || num_of_stacks < 2 {
+            break;
+        }
+
+        let origin = origins[rng.gen_range(0..origins.len())];
+        let mut destination = rng.gen_range(0..num_of_stacks - 1);
+        if destination >= origin {
+            destination += 1;
+        }
+        let element_to_move = rng.gen_range(1..heights[origin]);
+
+        heights[origin] -= element_to_move;
+        heights[destination] += element_to_move;
+        writeln!(input, "move {} from {} to {}", element_to_move, origin + 1, destination + 1).unwrap();
+    }
+
+    input
+}
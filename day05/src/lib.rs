@@ -0,0 +1,144 @@
+pub mod generator;
+pub mod loader;
+pub mod reference;
+pub mod stack;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+pub use loader::{parse_input, read_input, Input};
+use loader::Movement;
+use stack::CharStack;
+
+
+fn pop_elements_from_stack(stacks: &mut [CharStack], movement: &Movement) -> Vec<char> {
+    let mut crates_in_origin: Vec<char> = Vec::new();
+    let origin = &mut stacks[(movement.origin_stack - 1) as usize];
+
+    // The loader checks the origin holds enough crates.
+    crates_in_origin.extend((0..movement.element_to_move).map_while(|_| origin.pop()));
+    crates_in_origin
+}
+
+fn push_elements_in_stack(stacks: &mut [CharStack], movement: &Movement, mut crates: Vec<char>, move_in_batch: bool) {
+    if move_in_batch {
+        crates.reverse();
+    }
+
+    let destination = &mut stacks[(movement.destination_stack - 1) as usize];
+    for ch in crates {
+        destination.push(ch);
+    }
+}
+
+fn execute_movement(stacks: &mut [CharStack], movement: &Movement, move_in_batch: bool) {
+    let crates_to_move = pop_elements_from_stack(stacks, movement);
+    push_elements_in_stack(stacks, movement, crates_to_move, move_in_batch);
+}
+
+fn apply_movements(stacks: &mut [CharStack], movements: &[Movement], move_in_batch: bool) {
+    for movement in movements {
+        execute_movement(stacks, movement, move_in_batch);
+    }
+}
+
+pub fn step_1(input: &Input) -> String {
+    let mut stacks = input.stacks.clone();
+    apply_movements(&mut stacks, &input.movements, false);
+
+    concat_crates_on_top(&stacks)
+}
+
+
+pub fn step_2(input: &Input) -> String {
+    let mut stacks = input.stacks.clone();
+    apply_movements(&mut stacks, &input.movements, true);
+
+    concat_crates_on_top(&stacks)
+}
+
+/// The crates on top of the stacks; the empty stacks are skipped.
+fn concat_crates_on_top(stacks: &[CharStack]) -> String {
+    let mut crates_on_top = String::new();
+    for crate_on_top in stacks.iter().filter_map(CharStack::top) {
+        crates_on_top.push(crate_on_top);
+    }
+    crates_on_top
+}
+
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn solve_str(input: &str) -> Result<(String, String), ParseError> {
+    let parsed = parse_input(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn default_input(&self) -> &'static str {
+        DEFAULT_INPUT
+    }
+
+    fn step_1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_1(&parse_input(input)?).into())
+    }
+
+    fn step_2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_2(&parse_input(input)?).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    #[test]
+    fn loads_stacks_and_movements() {
+        let input = read_input(EXAMPLE).unwrap();
+
+        assert_eq!(concat_crates_on_top(&input.stacks), "NDP");
+        assert_eq!(input.movements.len(), 4);
+        assert_eq!(input.movements[1].element_to_move, 3);
+        assert_eq!(input.movements[1].origin_stack, 1);
+        assert_eq!(input.movements[1].destination_stack, 3);
+    }
+
+    #[test]
+    fn rejects_stacks_that_are_not_numbered_in_order() {
+        let err = parse_input("[A]     [B]\n 1   99999999999 \n\nmove 1 from 1 to 2\n").unwrap_err();
+
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 6, "99999999999"));
+    }
+
+    #[test]
+    fn rejects_movements_taking_more_crates_than_the_stack_holds() {
+        let err = parse_input("[A]    \n 1   2 \n\nmove 1 from 1 to 2\nmove 2 from 2 to 1\n").unwrap_err();
+
+        assert_eq!((err.line, err.column, err.message.as_str()), (5, 6, "stack 2 only holds 1 crate(s) at this point"));
+    }
+
+    #[test]
+    fn skips_the_stacks_left_empty() {
+        let input = parse_input("[A]    \n 1   2 \n\nmove 1 from 1 to 2\n").unwrap();
+
+        assert_eq!(step_1(&input), "A");
+        assert_eq!(step_2(&input), "A");
+    }
+
+    #[test]
+    fn step_1_moves_one_crate_at_a_time() {
+        assert_eq!(step_1(&read_input(EXAMPLE).unwrap()), "CMZ");
+    }
+
+    #[test]
+    fn step_2_moves_crates_in_batch() {
+        assert_eq!(step_2(&read_input(EXAMPLE).unwrap()), "MCD");
+    }
+}
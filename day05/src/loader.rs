@@ -1,99 +1,137 @@
-use std::fs;
-
+use aoc_common::{Error, ParseError};
 use regex::Regex;
 
 use crate::stack::CharStack;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Movement {
     pub element_to_move: u32,
     pub origin_stack: u32,
     pub destination_stack: u32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Input {
     pub stacks: Vec<CharStack>,
     pub movements: Vec<Movement>,
 }
 
 
-pub fn read_input(filename: &str) -> Input {
-    let data_from_file = fs::read_to_string(filename)
-        .expect("Cannot read the input")
-        .split('\n')
-        .map(String::from)
-        .collect();
+pub fn read_input(filename: &str) -> Result<Input, Error> {
+    aoc_common::parse_file(filename, parse_input)
+}
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let data_from_file: Vec<&str> = input.split('\n').collect();
 
-    let end_of_stacks_definition = get_end_of_stacks_definition(&data_from_file);
-    let stacks = parse_initial_stacks_state(&data_from_file, end_of_stacks_definition);
-    let movements = parse_movements(&data_from_file, end_of_stacks_definition);
+    let end_of_stacks_definition = get_end_of_stacks_definition(&data_from_file)?;
+    let stacks = parse_initial_stacks_state(&data_from_file, end_of_stacks_definition)?;
+    let movements = parse_movements(&data_from_file, end_of_stacks_definition, &stacks)?;
 
-    Input { stacks , movements }
+    Ok(Input { stacks , movements })
 }
 
-fn get_end_of_stacks_definition(data_from_file: &Vec<String>) -> u32 {
-    for idx in 0..data_from_file.len() {
-        match data_from_file[idx].chars().nth(1).unwrap().to_digit(10) {
-            Some(_) => return idx as u32,
+fn get_end_of_stacks_definition(data_from_file: &[&str]) -> Result<usize, ParseError> {
+    for (idx, row) in data_from_file.iter().enumerate() {
+        match row.chars().nth(1).and_then(|ch| ch.to_digit(10)) {
+            Some(_) => return Ok(idx),
             None => continue,
         }
     }
 
-    panic!("Apparently, the list of stacks has no end.");
+    let last_row = data_from_file.len();
+    Err(ParseError::new(last_row, 1, "", "", "apparently, the list of stacks has no end"))
 }
 
-fn parse_initial_stacks_state(data_from_file: &Vec<String>, end_of_stacks_definition: u32) -> Vec<CharStack> {
+fn parse_initial_stacks_state(data_from_file: &[&str], end_of_stacks_definition: usize) -> Result<Vec<CharStack>, ParseError> {
     let mut stacks: Vec<CharStack> = Vec::new();
 
-    let num_of_stacks = data_from_file[end_of_stacks_definition as usize].chars()
-        .nth_back(1)
-        .unwrap()
-        .to_digit(10)
-        .unwrap();
+    let stack_numbers = data_from_file[end_of_stacks_definition];
+    let mut num_of_stacks = 0;
+    for stack_number in stack_numbers.split_whitespace() {
+        num_of_stacks += 1;
+        if stack_number.parse::<usize>() != Ok(num_of_stacks) {
+            let message = format!("expected stack number {}", num_of_stacks);
+            return Err(ParseError::in_line(end_of_stacks_definition + 1, stack_numbers, stack_number, message));
+        }
+    }
 
     for _ in 1..(num_of_stacks + 1) {
         stacks.push(CharStack::new());
     }
 
-    for idx in (0..(end_of_stacks_definition + 1)).rev() {
-        let row = data_from_file[idx as usize].as_bytes();
+    for idx in (0..end_of_stacks_definition).rev() {
+        let row = data_from_file[idx].as_bytes();
 
         let mut current_stack_index: usize = 0;
         let mut possible_crate_index: usize = 1;
         while possible_crate_index < row.len() {
             let ch = row[possible_crate_index];
-            if ch.is_ascii_alphabetic() && ch != ' ' as u8 {
+            if ch.is_ascii_alphabetic() {
+                if current_stack_index >= num_of_stacks {
+                    let row = data_from_file[idx];
+                    let crate_name = &row[possible_crate_index..possible_crate_index + 1];
+                    return Err(ParseError::in_line(idx + 1, row, crate_name, format!("crate outside of the {} numbered stacks", num_of_stacks)));
+                }
                 stacks[current_stack_index].push(ch as char);
             }
             possible_crate_index += 4;
             current_stack_index += 1;
         }
     }
-    stacks
+    Ok(stacks)
 }
 
-fn parse_movements(data_from_file: &Vec<String>, end_of_stacks_definition: u32) -> Vec<Movement> {
+/// Reads the movements, checking each one takes at most the crates its origin stack holds then.
+fn parse_movements(data_from_file: &[&str], end_of_stacks_definition: usize, stacks: &[CharStack]) -> Result<Vec<Movement>, ParseError> {
+    let num_of_stacks = stacks.len();
+    let mut heights: Vec<usize> = stacks.iter().map(|stack| stack.crates().len()).collect();
     let mut movements: Vec<Movement> = Vec::new();
-    let start_of_movement = (end_of_stacks_definition + 2) as usize;
+    let start_of_movement = end_of_stacks_definition + 2;
 
     let movement_regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
-    for row in &data_from_file[start_of_movement..] {
-        if row.len() == 0 {
+    for (idx, row) in data_from_file.iter().enumerate().skip(start_of_movement) {
+        let line_number = idx + 1;
+        if row.is_empty() {
             continue;
         }
 
         let captures = match movement_regex.captures(row) {
             Some(cap) => cap,
-            None => panic!("Cannot parse row {}", row),
+            None => return Err(ParseError::whole_line(line_number, row, "expected a movement like `move 1 from 2 to 3`")),
+        };
+
+        let parse_number = |group: usize| -> Result<u32, ParseError> {
+            let number = captures.get(group).map_or("", |m| m.as_str());
+            number.parse::<u32>()
+                .map_err(|_| ParseError::in_line(line_number, row, number, "number too large"))
         };
+        let parse_stack = |group: usize| -> Result<u32, ParseError> {
+            let stack = parse_number(group)?;
+            if stack == 0 || stack as usize > num_of_stacks {
+                let number = captures.get(group).map_or("", |m| m.as_str());
+                return Err(ParseError::in_line(line_number, row, number, format!("there is no stack {}", stack)));
+            }
+            Ok(stack)
+        };
+
+        let movement = Movement {
+            element_to_move: parse_number(1)?,
+            origin_stack: parse_stack(2)?,
+            destination_stack: parse_stack(3)?,
+        };
+
+        let origin_height = heights[movement.origin_stack as usize - 1];
+        if movement.element_to_move as usize > origin_height {
+            let number = captures.get(1).map_or("", |m| m.as_str());
+            let message = format!("stack {} only holds {} crate(s) at this point", movement.origin_stack, origin_height);
+            return Err(ParseError::in_line(line_number, row, number, message));
+        }
+        heights[movement.origin_stack as usize - 1] -= movement.element_to_move as usize;
+        heights[movement.destination_stack as usize - 1] += movement.element_to_move as usize;
 
-        movements.push(Movement {
-            element_to_move: captures.get(1).map_or("", |m| m.as_str()).parse::<u32>().unwrap(),
-            origin_stack: captures.get(2).map_or("", |m| m.as_str()).parse::<u32>().unwrap(),
-            destination_stack: captures.get(3).map_or("", |m| m.as_str()).parse::<u32>().unwrap(),
-        });
+        movements.push(movement);
     }
 
-    movements
+    Ok(movements)
 }
@@ -0,0 +1,42 @@
+//! Stacks are plain vectors, and crates moved together are split off the origin in one go.
+
+use aoc_common::ParseError;
+
+use crate::Input;
+
+fn initial_stacks(input: &Input) -> Vec<Vec<char>> {
+    input.stacks.iter().map(|stack| stack.crates().to_vec()).collect()
+}
+
+fn crates_on_top(stacks: &[Vec<char>]) -> String {
+    stacks.iter().map(|stack| *stack.last().unwrap()).collect()
+}
+
+pub fn step_1(input: &Input) -> String {
+    let mut stacks = initial_stacks(input);
+    for movement in &input.movements {
+        for _ in 0..movement.element_to_move {
+            let moved_crate = stacks[movement.origin_stack as usize - 1].pop().unwrap();
+            stacks[movement.destination_stack as usize - 1].push(moved_crate);
+        }
+    }
+
+    crates_on_top(&stacks)
+}
+
+pub fn step_2(input: &Input) -> String {
+    let mut stacks = initial_stacks(input);
+    for movement in &input.movements {
+        let origin = &mut stacks[movement.origin_stack as usize - 1];
+        let moved_crates = origin.split_off(origin.len() - movement.element_to_move as usize);
+        stacks[movement.destination_stack as usize - 1].extend(moved_crates);
+    }
+
+    crates_on_top(&stacks)
+}
+
+pub fn solve_str(input: &str) -> Result<(String, String), ParseError> {
+    let parsed = crate::parse_input(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
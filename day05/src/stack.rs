@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Debug, Default, Clone)]
 pub struct CharStack {
     data: Vec<char>,
 }
@@ -12,14 +12,16 @@ impl CharStack {
         self.data.push(character);
     }
 
-    pub fn pop(&mut self) -> char {
-        match self.data.pop() {
-            Some(value) => value,
-            None => panic!("You called pop on an empty stack!")
-        }
+    pub fn pop(&mut self) -> Option<char> {
+        self.data.pop()
     }
 
-    pub fn top(&self) -> char {
-        self.data[self.data.len() - 1]
+    pub fn top(&self) -> Option<char> {
+        self.data.last().copied()
+    }
+
+    /// The crates from the bottom of the stack to the top.
+    pub fn crates(&self) -> &[char] {
+        &self.data
     }
 }
\ No newline at end of file
@@ -0,0 +1,34 @@
+use clap::Args;
+use rand::{seq::SliceRandom, Rng};
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+#[derive(Args, Debug, Clone)]
+pub struct Params {
+    /// Number of characters in the datastream
+    #[arg(long, default_value_t = 4096)]
+    pub length: usize,
+
+    /// Number of letters the noise before the final marker is drawn from
+    #[arg(long, default_value_t = 10)]
+    pub alphabet: usize,
+}
+
+/// The datastream always ends with 14 different letters, so both markers exist.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, params: &Params) -> String {
+    let alphabet = &LETTERS[..params.alphabet.clamp(1, LETTERS.len())];
+    let noise_length = params.length.saturating_sub(14);
+
+    let mut datastream: Vec<u8> = (0..noise_length)
+        .map(|_| alphabet[rng.gen_range(0..alphabet.len())])
+        .collect();
+
+    let mut marker = LETTERS.to_vec();
+    marker.shuffle(rng);
+    datastream.extend(&marker[..14]);
+
+    let mut input: String = datastream.into_iter().map(char::from).collect();
+    input.push('\n');
+
+    input
+}
@@ -0,0 +1,147 @@
+pub mod generator;
+pub mod reference;
+
+use std::collections::HashSet;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+fn are_all_unique(window: &[char]) -> bool {
+    let mut uniques: HashSet<char> = HashSet::new();
+
+    uniques.extend(window);
+
+    uniques.len() == window.len()
+}
+
+const START_OF_PACKET_SIZE: u32 = 4;
+const START_OF_MESSAGE_SIZE: u32 = 14;
+
+pub fn step_1(input: &str) -> Option<u32> {
+    find_marker(input, START_OF_PACKET_SIZE)
+}
+
+pub fn step_2(input: &str) -> Option<u32> {
+    find_marker(input, START_OF_MESSAGE_SIZE)
+}
+
+/// The marker found by a step, or an error about the datastream when it has none.
+fn require_marker(input: &str, marker: Option<u32>, unique_character_size_before_marker: u32) -> Result<u32, ParseError> {
+    marker.ok_or_else(|| {
+        let datastream = input.lines().next().unwrap_or("");
+        ParseError::whole_line(1, datastream, format!("no {} characters in a row are all different, there is no marker", unique_character_size_before_marker))
+    })
+}
+
+
+/// Number of characters read when the last `unique_character_size_before_marker` ones are all
+/// different; the line ending is not part of the datastream.
+fn find_marker(input: &str, unique_character_size_before_marker: u32) -> Option<u32> {
+    let datastream: Vec<char> = input.trim_end_matches(['\n', '\r']).chars().collect();
+
+    let windows_size = unique_character_size_before_marker as usize;
+    let mut end_of_window: usize = windows_size - 1;
+    while end_of_window < datastream.len() {
+        let window_start = end_of_window - (windows_size - 1);
+        let next_after_window = end_of_window + 1;
+        if are_all_unique(&datastream[window_start..next_after_window]) {
+            return Some(next_after_window as u32)
+        }
+        end_of_window += 1;
+    }
+
+    None
+}
+
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn solve_str(input: &str) -> Result<(u32, u32), ParseError> {
+    Ok((require_marker(input, step_1(input), START_OF_PACKET_SIZE)?, require_marker(input, step_2(input), START_OF_MESSAGE_SIZE)?))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn default_input(&self) -> &'static str {
+        DEFAULT_INPUT
+    }
+
+    fn step_1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(require_marker(input, step_1(input), START_OF_PACKET_SIZE)?.into())
+    }
+
+    fn step_2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(require_marker(input, step_2(input), START_OF_MESSAGE_SIZE)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::read_input;
+
+    use super::*;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    #[test]
+    fn detects_windows_of_unique_characters() {
+        assert!(are_all_unique(&['j', 'p', 'q', 'm']));
+        assert!(!are_all_unique(&['m', 'j', 'q', 'j']));
+    }
+
+    #[test]
+    fn finds_the_start_of_packet_marker() {
+        assert_eq!(find_marker("bvwbjplbgvbhsrlpgdmjqwftvncz", 4), Some(5));
+        assert_eq!(find_marker("nppdvjthqldpwncqszvftbrmjlhg", 4), Some(6));
+        assert_eq!(find_marker("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4), Some(10));
+        assert_eq!(find_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4), Some(11));
+    }
+
+    #[test]
+    fn finds_the_start_of_message_marker() {
+        assert_eq!(find_marker("bvwbjplbgvbhsrlpgdmjqwftvncz", 14), Some(23));
+        assert_eq!(find_marker("nppdvjthqldpwncqszvftbrmjlhg", 14), Some(23));
+        assert_eq!(find_marker("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14), Some(29));
+        assert_eq!(find_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14), Some(26));
+    }
+
+    #[test]
+    fn does_not_find_a_marker_in_repeated_characters() {
+        assert_eq!(find_marker("aaaaaaaa", 4), None);
+    }
+
+    #[test]
+    fn step_1_finds_the_packet_marker() {
+        assert_eq!(step_1(&read_input(EXAMPLE).unwrap()), Some(7));
+    }
+
+    #[test]
+    fn step_2_finds_the_message_marker() {
+        assert_eq!(step_2(&read_input(EXAMPLE).unwrap()), Some(19));
+    }
+
+    #[test]
+    fn reports_a_datastream_without_marker() {
+        let err = solve_str("abcabcabcabc").unwrap_err();
+
+        assert_eq!((err.line, err.message.as_str()), (1, "no 4 characters in a row are all different, there is no marker"));
+        assert!(Day06.step_2("abcdabcdabcd").is_err());
+    }
+
+    #[test]
+    fn does_not_count_the_line_ending_in_the_datastream() {
+        assert_eq!(find_marker("abc\n", 4), None);
+        assert_eq!(find_marker("abcabc\r\n", 4), None);
+        assert_eq!(find_marker("abcd\n", 4), Some(4));
+    }
+
+    #[test]
+    fn counts_characters_of_non_ascii_datastreams() {
+        assert_eq!(find_marker("éééé", 4), None);
+        assert_eq!(find_marker("ééàèçù", 4), Some(5));
+        assert!(!are_all_unique(&['é', 'a', 'é']));
+    }
+}
@@ -0,0 +1,26 @@
+//! Every window is checked by comparing each pair of its characters.
+
+use aoc_common::ParseError;
+
+fn find_marker(input: &str, window_size: usize) -> u32 {
+    let characters: Vec<char> = input.chars().collect();
+
+    (window_size..=characters.len())
+        .find(|&end| {
+            let window = &characters[end - window_size..end];
+            (0..window_size).all(|first| (first + 1..window_size).all(|second| window[first] != window[second]))
+        })
+        .expect("Cannot find a marker") as u32
+}
+
+pub fn step_1(input: &str) -> u32 {
+    find_marker(input, 4)
+}
+
+pub fn step_2(input: &str) -> u32 {
+    find_marker(input, 14)
+}
+
+pub fn solve_str(input: &str) -> Result<(u32, u32), ParseError> {
+    Ok((step_1(input), step_2(input)))
+}
@@ -0,0 +1,107 @@
+use std::{collections::HashSet, fmt::Write};
+
+use clap::Args;
+use rand::Rng;
+
+const DISK_SIZE: u64 = 70000000;
+const FREE_SPACE_NEEDED: u64 = 30000000;
+
+#[derive(Args, Debug, Clone)]
+pub struct Params {
+    /// Depth of the directory tree
+    #[arg(long, default_value_t = 6)]
+    pub depth: usize,
+
+    /// Most subdirectories in a single directory
+    #[arg(long, default_value_t = 3)]
+    pub max_subdirectories: usize,
+
+    /// Most files in a single directory
+    #[arg(long, default_value_t = 5)]
+    pub max_files: usize,
+}
+
+struct GeneratedDirectory {
+    files: Vec<(String, u64)>,
+    subdirectories: Vec<(String, GeneratedDirectory)>,
+}
+
+fn unique_name<R: Rng + ?Sized>(rng: &mut R, used_names: &mut HashSet<String>, with_extension: bool) -> String {
+    loop {
+        let mut name: String = (0..rng.gen_range(1..=8)).map(|_| rng.gen_range('a'..='z')).collect();
+        if with_extension && rng.gen_bool(0.5) {
+            name.push('.');
+            name.extend((0..3).map(|_| rng.gen_range('a'..='z')));
+        }
+        if used_names.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+fn generate_directory<R: Rng + ?Sized>(rng: &mut R, params: &Params, depth: usize) -> GeneratedDirectory {
+    let mut used_names = HashSet::new();
+    // The root always holds a file, which absorbs the rounding when sizes are scaled.
+    let min_files = if depth == 0 { 1 } else { 0 };
+
+    let files = (0..rng.gen_range(min_files..=params.max_files.max(min_files)))
+        .map(|_| (unique_name(rng, &mut used_names, true), rng.gen_range(1..=300000)))
+        .collect();
+
+    let mut subdirectories = Vec::new();
+    if depth < params.depth {
+        for _ in 0..rng.gen_range(1..=params.max_subdirectories.max(1)) {
+            let name = unique_name(rng, &mut used_names, false);
+            subdirectories.push((name, generate_directory(rng, params, depth + 1)));
+        }
+    }
+
+    GeneratedDirectory { files, subdirectories }
+}
+
+fn total_size(directory: &GeneratedDirectory) -> u64 {
+    directory.files.iter().map(|(_, size)| size).sum::<u64>()
+        + directory.subdirectories.iter().map(|(_, subdir)| total_size(subdir)).sum::<u64>()
+}
+
+fn scale_sizes(directory: &mut GeneratedDirectory, numerator: u64, denominator: u64) {
+    for (_, size) in &mut directory.files {
+        *size = ((*size as u128 * numerator as u128 / denominator as u128) as u64).max(1);
+    }
+    for (_, subdir) in &mut directory.subdirectories {
+        scale_sizes(subdir, numerator, denominator);
+    }
+}
+
+fn write_history(history: &mut String, directory: &GeneratedDirectory) {
+    history.push_str("$ ls\n");
+    for (name, _) in &directory.subdirectories {
+        writeln!(history, "dir {}", name).unwrap();
+    }
+    for (name, size) in &directory.files {
+        writeln!(history, "{} {}", size, name).unwrap();
+    }
+
+    for (name, subdir) in &directory.subdirectories {
+        writeln!(history, "$ cd {}", name).unwrap();
+        write_history(history, subdir);
+        history.push_str("$ cd ..\n");
+    }
+}
+
+/// The disk usage is kept between the space needed by the update and the disk size,
+/// so part 2 always has something to delete.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, params: &Params) -> String {
+    let mut root = generate_directory(rng, params, 0);
+
+    let target_size = rng.gen_range(DISK_SIZE - FREE_SPACE_NEEDED + 1..=DISK_SIZE);
+    let generated_size = total_size(&root);
+    scale_sizes(&mut root, target_size, generated_size);
+    let missing_size = target_size.saturating_sub(total_size(&root));
+    root.files[0].1 += missing_size;
+
+    let mut history = String::from("$ cd /\n");
+    write_history(&mut history, &root);
+
+    history
+}
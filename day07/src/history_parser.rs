@@ -1,5 +1,6 @@
-use std::{collections::HashMap, fs};
+use std::collections::HashMap;
 
+use aoc_common::{lines, Error, ParseError};
 use regex::Regex;
 
 #[derive(Debug)]
@@ -22,7 +23,7 @@ pub struct Directory {
 }
 
 impl Directory {
-    fn new(name: &String) -> Directory {
+    fn new(name: &str) -> Directory {
         Directory { name: String::from(name), subdirectories: Vec::new(), files: Vec::new() }
     }
 }
@@ -34,27 +35,50 @@ impl Clone for Directory {
 }
 
 struct CommandExecution {
-    pub command: String,
-    pub output: Vec<String>,
+    pub line: usize,
+    pub source_line: String,
+    pub output: Vec<(usize, String)>,
 }
 
 impl CommandExecution {
-    fn from_string(command: String) -> CommandExecution {
-        let rows: Vec<&str> = command.split('\n').collect();
-
-        let command = String::from(rows[0].trim());
-        let output: Vec<String> = rows[1..].iter()
-            .map(|output_row| String::from(*output_row))
-            .filter(|row| row.len() > 0)
-            .collect();
+    fn new(line: usize, source_line: &str) -> CommandExecution {
+        CommandExecution { line, source_line: String::from(source_line), output: Vec::new() }
+    }
 
-        CommandExecution { command, output }
+    fn command(&self) -> &str {
+        self.source_line.trim_start_matches('$').trim()
     }
 
     fn is_change_dir_command(&self) -> bool {
         let change_dir_regex = Regex::new(r"^cd (.+)$").unwrap();
-        change_dir_regex.is_match(&self.command)
+        change_dir_regex.is_match(self.command())
+    }
+
+    fn is_list_command(&self) -> bool {
+        self.command() == "ls"
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        ParseError::in_line(self.line, &self.source_line, self.command(), message)
+    }
+}
+
+fn parse_history(input: &str) -> Result<Vec<CommandExecution>, ParseError> {
+    let mut history: Vec<CommandExecution> = Vec::new();
+
+    for (line_number, row) in lines(input) {
+        if row.starts_with('$') {
+            history.push(CommandExecution::new(line_number, row));
+            continue;
+        }
+
+        match history.last_mut() {
+            Some(command) => command.output.push((line_number, String::from(row))),
+            None => return Err(ParseError::whole_line(line_number, row, "command output found before any command")),
+        }
     }
+
+    Ok(history)
 }
 
 
@@ -62,6 +86,8 @@ struct FileSystemDiscoverer {
     directories: HashMap<String, Directory>,
     current_directory: String,
     directory_traversal_history: Vec<String>,
+    /// Every `dir` entry of the `ls` outputs: the path of the subdirectory, its line and the entry.
+    listed_subdirectories: Vec<(String, usize, String)>,
 }
 
 impl FileSystemDiscoverer {
@@ -69,44 +95,74 @@ impl FileSystemDiscoverer {
         FileSystemDiscoverer { directories: HashMap::new(),
             current_directory: String::from("") ,
             directory_traversal_history: Vec::new(),
+            listed_subdirectories: Vec::new(),
         }
     }
 
-    fn explore_history(&mut self, commands: &Vec<CommandExecution>) {
+    fn explore_history(&mut self, commands: &[CommandExecution]) -> Result<(), ParseError> {
         for command in commands {
             if command.is_change_dir_command() {
-                self.parse_change_dir_command(command);
+                self.parse_change_dir_command(command)?;
+            } else if command.is_list_command() {
+                self.parse_ls_command(command)?;
             } else {
-                self.parse_ls_command(command);
+                return Err(command.error("unknown command, expected `cd` or `ls`"));
             }
         }
+
+        Ok(())
     }
 
-    fn parse_ls_command(&mut self, ls_command: &CommandExecution) {
+    fn parse_ls_command(&mut self, ls_command: &CommandExecution) -> Result<(), ParseError> {
         let mut current_directory = Directory::new(&self.current_directory);
 
-        for row in &ls_command.output {
-            FileSystemDiscoverer::update_directory_with_new_entry(&mut current_directory, row);
+        for (line_number, row) in &ls_command.output {
+            FileSystemDiscoverer::update_directory_with_new_entry(&mut current_directory, *line_number, row)?;
+            if row.starts_with("dir ") {
+                let subdir = current_directory.subdirectories.last().unwrap().clone();
+                self.listed_subdirectories.push((subdir, *line_number, row.clone()));
+            }
         }
 
         self.directories.insert(self.current_directory.clone(), current_directory);
+
+        Ok(())
     }
 
-    fn update_directory_with_new_entry(directory: &mut Directory, row_from_ls: &String) {
-        if row_from_ls.starts_with("dir") {
-            let child_name = String::from(row_from_ls.split(' ').next_back().unwrap());
-            let subdir = FileSystemDiscoverer::add_child_to_path(&directory.name, &child_name);
+    fn update_directory_with_new_entry(directory: &mut Directory, line_number: usize, row_from_ls: &str) -> Result<(), ParseError> {
+        if let Some(child_name) = row_from_ls.strip_prefix("dir ") {
+            FileSystemDiscoverer::check_entry_name(line_number, row_from_ls, child_name)?;
+            let subdir = FileSystemDiscoverer::add_child_to_path(&directory.name, child_name);
             directory.subdirectories.push(subdir);
-        } else {
-            let file_definition: Vec<&str> = row_from_ls.split(' ').collect();
-            directory.files.push(File { name: String::from(file_definition[1]), size: file_definition[0].parse::<u64>().unwrap() })
+            return Ok(());
         }
+
+        let (size, name) = row_from_ls.split_once(' ')
+            .ok_or_else(|| ParseError::whole_line(line_number, row_from_ls, "expected `dir <name>` or `<size> <name>`"))?;
+        let size = size.parse::<u64>()
+            .map_err(|_| ParseError::in_line(line_number, row_from_ls, size, "expected a file size"))?;
+        FileSystemDiscoverer::check_entry_name(line_number, row_from_ls, name)?;
+
+        directory.files.push(File { name: String::from(name), size });
+
+        Ok(())
     }
 
-    fn parse_change_dir_command(&mut self, chdir_command: &CommandExecution) {
-        match chdir_command.command.as_str() {
-            "cd .." => self.go_back_to_parent(),
-            _ => self.change_current_folder(FileSystemDiscoverer::get_directory_from_change_dir_command(chdir_command)),
+    /// An empty name would make `/` its own subdirectory.
+    fn check_entry_name(line_number: usize, row_from_ls: &str, name: &str) -> Result<(), ParseError> {
+        match name.trim().is_empty() {
+            true => Err(ParseError::in_line(line_number, row_from_ls, name, "expected the name of the entry")),
+            false => Ok(()),
+        }
+    }
+
+    fn parse_change_dir_command(&mut self, chdir_command: &CommandExecution) -> Result<(), ParseError> {
+        match chdir_command.command() {
+            "cd .." => self.go_back_to_parent(chdir_command),
+            _ => {
+                self.change_current_folder(FileSystemDiscoverer::get_directory_from_change_dir_command(chdir_command));
+                Ok(())
+            }
         }
     }
 
@@ -114,30 +170,45 @@ impl FileSystemDiscoverer {
         let new_current_folder_id = FileSystemDiscoverer::add_child_to_path(&self.current_directory, &new_current_folder);
 
         self.directory_traversal_history.push(new_current_folder_id.clone());
-        self.current_directory = String::from(new_current_folder_id.clone());
+        self.current_directory = new_current_folder_id;
     }
 
-    fn add_child_to_path(path: &String, child: &String) -> String {
-        let sep;
-        if path == "/" || child == "/" {
-            sep = "";
+    fn add_child_to_path(path: &str, child: &str) -> String {
+        let sep = if path == "/" || child == "/" {
+            ""
         } else {
-            sep = "/";
-        }
+            "/"
+        };
 
         format!("{}{}{}", path, sep, child)
     }
 
-    fn go_back_to_parent(&mut self) {
-        let parent = self.get_parent();
+    fn go_back_to_parent(&mut self, chdir_command: &CommandExecution) -> Result<(), ParseError> {
+        let parent = self.get_parent()
+            .ok_or_else(|| chdir_command.error("you are at the top of the hierarchy"))?;
         self.current_directory = parent;
+
+        Ok(())
+    }
+
+    fn get_parent(&mut self) -> Option<String> {
+        self.directory_traversal_history.pop()?;
+        self.directory_traversal_history.last().cloned()
     }
 
-    fn get_parent(&mut self) -> String {
-        match self.directory_traversal_history.pop() {
-            Some(_) => self.directory_traversal_history.iter().next_back().unwrap().clone(),
-            None => panic!("You are at the top of the hierachy"),
+    /// Checks the sizes of the directories can be told: the root and every subdirectory were listed.
+    fn check_explored(&self) -> Result<(), ParseError> {
+        if !self.directories.contains_key("/") {
+            return Err(ParseError::whole_line(1, "", "the history never lists the content of `/`"));
         }
+
+        for (subdir, line_number, row) in &self.listed_subdirectories {
+            if !self.directories.contains_key(subdir) {
+                return Err(ParseError::whole_line(*line_number, row, format!("the content of `{}` is never listed", subdir)));
+            }
+        }
+
+        Ok(())
     }
 
     fn get_discovered_folders(&self) -> HashMap<String, Directory> {
@@ -147,26 +218,26 @@ impl FileSystemDiscoverer {
     fn get_directory_from_change_dir_command(command: &CommandExecution) -> String {
         let change_dir_regex = Regex::new(r"^cd (.+)$").unwrap();
 
-        let captures = match change_dir_regex.captures(&command.command) {
+        let captures = match change_dir_regex.captures(command.command()) {
             Some(cap) => cap,
-            None => panic!("Failed to parse command {}", command.command),
+            None => panic!("Failed to parse command {}", command.command()),
         };
 
         captures.get(1).map(|cap| String::from(cap.as_str())).unwrap()
     }
 }
 
-pub fn infer_filesystem_from_history(filename: &str) -> HashMap<String, Directory> {
-    let history: Vec<CommandExecution> = fs::read_to_string(filename)
-        .expect("Cannot read the input")
-        .split('$')
-        .filter(|row| row.len() > 0)
-        .map(|row| CommandExecution::from_string(String::from(row)))
-        .collect();
+pub fn infer_filesystem_from_history(filename: &str) -> Result<HashMap<String, Directory>, Error> {
+    aoc_common::parse_file(filename, parse_filesystem)
+}
+
+pub fn parse_filesystem(input: &str) -> Result<HashMap<String, Directory>, ParseError> {
+    let history = parse_history(input)?;
 
     let mut discoverer = FileSystemDiscoverer::new();
 
-    discoverer.explore_history(&history);
+    discoverer.explore_history(&history)?;
+    discoverer.check_explored()?;
 
-    discoverer.get_discovered_folders()
+    Ok(discoverer.get_discovered_folders())
 }
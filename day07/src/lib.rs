@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+pub mod generator;
+pub mod history_parser;
+pub mod reference;
+
+use history_parser::Directory;
+
+struct DirectorySizeCalculator {
+    filesystem: HashMap<String, Directory>,
+    directory_size: HashMap<String, u64>,
+}
+
+impl DirectorySizeCalculator {
+    fn new(filesystem: &HashMap<String, Directory>) -> DirectorySizeCalculator {
+        DirectorySizeCalculator { filesystem: filesystem.clone(), directory_size: HashMap::new() }
+    }
+
+    fn get_directories_size(&mut self) -> HashMap<String, u64> {
+        self.calculate_directories_sizes();
+
+        self.directory_size.clone()
+    }
+
+    fn calculate_directories_sizes(&mut self) {
+        let mut directory_sizes: HashMap<String, u64> = HashMap::new();
+        self.filesystem.values().for_each(|subdir| {
+            self.calculate_directory_size_for_dir(subdir, &mut directory_sizes);
+        });
+
+        self.directory_size = directory_sizes;
+    }
+
+    fn calculate_directory_size_for_dir(&self, directory: &Directory, directory_size_cache: &mut HashMap<String, u64>) -> u64 {
+        let mut dir_size = directory.files.iter().map(|file| file.size).sum::<u64>();
+
+        for subdir in &directory.subdirectories {
+            // The parser checks every subdirectory was listed; a missing one is counted as empty.
+            let Some(subdir_obj) = self.filesystem.get(subdir) else {
+                continue;
+            };
+            let subdir_size = match directory_size_cache.get(subdir) {
+                Some(size) => *size,
+                None => self.calculate_directory_size_for_dir(subdir_obj, directory_size_cache)
+            };
+
+            dir_size += subdir_size;
+        }
+
+        directory_size_cache.insert(directory.name.clone(), dir_size);
+
+        dir_size
+    }
+}
+
+pub fn step_1(filesystem: &HashMap<String, Directory>) -> u64 {
+    let threshold: u64 = 100000;
+
+    let mut dir_size_calculator = DirectorySizeCalculator::new(filesystem);
+    dir_size_calculator.get_directories_size().values()
+        .filter(|&&dir_size| dir_size <= threshold)
+        .sum()
+}
+
+/// Size of the smallest directory to delete to make room for the update, 0 when there is already enough room.
+pub fn step_2(filesystem: &HashMap<String, Directory>) -> u64 {
+    let disk_size: u64 = 70000000;
+    let update_required_size: u64 = 30000000;
+
+    let dir_sizes = DirectorySizeCalculator::new(filesystem).get_directories_size();
+
+    let root_size = dir_sizes.get("/").copied().unwrap_or_default();
+    let size_to_free = update_required_size.saturating_sub(disk_size.saturating_sub(root_size));
+    if size_to_free == 0 {
+        return 0;
+    }
+
+    // The root itself is always big enough, as it holds more than what needs to be freed.
+    dir_sizes.values().copied().filter(|&dir_size| dir_size > size_to_free).min().unwrap_or(root_size)
+}
+
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn solve_str(input: &str) -> Result<(u64, u64), ParseError> {
+    let parsed = history_parser::parse_filesystem(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn default_input(&self) -> &'static str {
+        DEFAULT_INPUT
+    }
+
+    fn step_1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_1(&history_parser::parse_filesystem(input)?).into())
+    }
+
+    fn step_2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_2(&history_parser::parse_filesystem(input)?).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    #[test]
+    fn infers_the_filesystem_from_the_history() {
+        let filesystem = history_parser::infer_filesystem_from_history(EXAMPLE).unwrap();
+
+        assert_eq!(filesystem.len(), 4);
+        assert_eq!(filesystem["/"].subdirectories, vec!["/a", "/d"]);
+        assert_eq!(filesystem["/a/e"].files.len(), 1);
+        assert_eq!(filesystem["/a/e"].files[0].size, 584);
+    }
+
+    #[test]
+    fn calculates_the_size_of_every_directory() {
+        let filesystem = history_parser::infer_filesystem_from_history(EXAMPLE).unwrap();
+        let dir_sizes = DirectorySizeCalculator::new(&filesystem).get_directories_size();
+
+        assert_eq!(dir_sizes["/a/e"], 584);
+        assert_eq!(dir_sizes["/a"], 94853);
+        assert_eq!(dir_sizes["/d"], 24933642);
+        assert_eq!(dir_sizes["/"], 48381165);
+    }
+
+    #[test]
+    fn step_1_sums_the_small_directories() {
+        let filesystem = history_parser::infer_filesystem_from_history(EXAMPLE).unwrap();
+
+        assert_eq!(step_1(&filesystem), 95437);
+    }
+
+    #[test]
+    fn step_2_finds_the_smallest_directory_to_delete() {
+        let filesystem = history_parser::infer_filesystem_from_history(EXAMPLE).unwrap();
+
+        assert_eq!(step_2(&filesystem), 24933642);
+    }
+
+    #[test]
+    fn rejects_histories_that_do_not_list_every_directory() {
+        let err = history_parser::parse_filesystem("$ cd /\n$ ls\ndir a\n").unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (3, "the content of `/a` is never listed"));
+
+        let err = history_parser::parse_filesystem("$ cd a\n$ ls\n12 b\n").unwrap_err();
+        assert_eq!(err.message, "the history never lists the content of `/`");
+    }
+
+    #[test]
+    fn rejects_entries_without_a_name() {
+        let err = history_parser::parse_filesystem("$ cd /\n$ ls\ndir \n").unwrap_err();
+        assert_eq!((err.line, err.column, err.message.as_str()), (3, 5, "expected the name of the entry"));
+
+        assert!(history_parser::parse_filesystem("$ cd /\n$ ls\ndir  \t\n").is_err());
+        assert!(history_parser::parse_filesystem("$ cd /\n$ ls\n12 \n").is_err());
+    }
+
+    #[test]
+    fn step_2_deletes_nothing_when_there_is_enough_room() {
+        let filesystem = history_parser::parse_filesystem("$ cd /\n$ ls\n100 a\n").unwrap();
+
+        assert_eq!(step_2(&filesystem), 0);
+    }
+
+    #[test]
+    fn step_2_handles_a_root_bigger_than_the_disk() {
+        let filesystem = history_parser::parse_filesystem("$ cd /\n$ ls\ndir a\n75000000 b\n$ cd a\n$ ls\n10 c\n").unwrap();
+
+        assert_eq!(step_2(&filesystem), 75000010);
+    }
+}
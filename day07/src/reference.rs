@@ -0,0 +1,41 @@
+//! The size of a directory is recomputed from scratch by scanning every directory
+//! of the filesystem for the ones below it, without any cache.
+
+use std::collections::HashMap;
+
+use aoc_common::ParseError;
+
+use crate::history_parser::Directory;
+
+fn is_inside(path: &str, directory: &str) -> bool {
+    let prefix = if directory == "/" { String::from("/") } else { format!("{}/", directory) };
+
+    path == directory || path.starts_with(&prefix)
+}
+
+fn directory_size(filesystem: &HashMap<String, Directory>, directory: &str) -> u64 {
+    filesystem.values()
+        .filter(|other| is_inside(&other.name, directory))
+        .flat_map(|other| other.files.iter().map(|file| file.size))
+        .sum()
+}
+
+fn directory_sizes(filesystem: &HashMap<String, Directory>) -> Vec<u64> {
+    filesystem.keys().map(|directory| directory_size(filesystem, directory)).collect()
+}
+
+pub fn step_1(filesystem: &HashMap<String, Directory>) -> u64 {
+    directory_sizes(filesystem).into_iter().filter(|&size| size <= 100000).sum()
+}
+
+pub fn step_2(filesystem: &HashMap<String, Directory>) -> u64 {
+    let size_to_free = 30000000 - (70000000 - directory_size(filesystem, "/"));
+
+    directory_sizes(filesystem).into_iter().filter(|&size| size > size_to_free).min().unwrap()
+}
+
+pub fn solve_str(input: &str) -> Result<(u64, u64), ParseError> {
+    let parsed = crate::history_parser::parse_filesystem(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
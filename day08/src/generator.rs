@@ -0,0 +1,24 @@
+use clap::Args;
+use rand::Rng;
+
+#[derive(Args, Debug, Clone)]
+pub struct Params {
+    /// Number of trees in a row
+    #[arg(long, default_value_t = 99)]
+    pub width: usize,
+
+    /// Number of rows
+    #[arg(long, default_value_t = 99)]
+    pub height: usize,
+}
+
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, params: &Params) -> String {
+    let mut input = String::new();
+
+    for _ in 0..params.height.max(1) {
+        input.extend((0..params.width.max(1)).map(|_| char::from(b'0' + rng.gen_range(0..10))));
+        input.push('\n');
+    }
+
+    input
+}
@@ -0,0 +1,42 @@
+pub mod generator;
+pub mod reference;
+pub mod tree_visibility;
+pub mod tree_scenic_score;
+
+use aoc_common::{Answer, Error, Grid, ParseError, Solution};
+
+pub fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse_digits(input)
+}
+
+pub fn read_input(filename: &str) -> Result<Grid<u32>, Error> {
+    aoc_common::parse_file(filename, parse_input)
+}
+
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn solve_str(input: &str) -> Result<(u32, u32), ParseError> {
+    let parsed = parse_input(input)?;
+
+    Ok((tree_visibility::step_1(&parsed), tree_scenic_score::step_2(&parsed)))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn default_input(&self) -> &'static str {
+        DEFAULT_INPUT
+    }
+
+    fn step_1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(tree_visibility::step_1(&parse_input(input)?).into())
+    }
+
+    fn step_2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(tree_scenic_score::step_2(&parse_input(input)?).into())
+    }
+}
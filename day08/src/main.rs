@@ -1,7 +1,24 @@
-mod tree_visibility;
-mod tree_scenic_score;
+use std::process::ExitCode;
 
-fn main() {
-    assert_eq!(tree_visibility::step_1("input.txt"), 1779);
-    assert_eq!(tree_scenic_score::step_2("input.txt"), 172224);
+use aoc_common::{Error, InputArgs};
+use clap::Parser;
+
+/// Day 8: Treetop Tree House
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+fn run(cli: &Cli) -> Result<(), Error> {
+    let (part_1, part_2) = cli.input.load(day08::DEFAULT_INPUT, day08::solve_str)?;
+
+    println!("Part 1: {}", part_1);
+    println!("Part 2: {}", part_2);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    aoc_common::exit_code(run(&Cli::parse()))
 }
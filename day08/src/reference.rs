@@ -0,0 +1,46 @@
+//! Every tree looks in the four directions on its own, without reusing what its neighbours saw.
+
+use aoc_common::{Grid, ParseError};
+
+/// The heights met walking from a tree towards each edge, closest first.
+fn lines_of_sight(height: &Grid<u32>, row: usize, col: usize) -> [Vec<u32>; 4] {
+    [
+        (0..row).rev().map(|idx| height[idx][col]).collect(),
+        (row + 1..height.height()).map(|idx| height[idx][col]).collect(),
+        (0..col).rev().map(|idx| height[row][idx]).collect(),
+        (col + 1..height.width()).map(|idx| height[row][idx]).collect(),
+    ]
+}
+
+fn trees(height: &Grid<u32>) -> impl Iterator<Item = (usize, usize)> + '_ {
+    (0..height.height()).flat_map(move |row| (0..height.width()).map(move |col| (row, col)))
+}
+
+pub fn step_1(height: &Grid<u32>) -> u32 {
+    trees(height)
+        .filter(|&(row, col)| {
+            lines_of_sight(height, row, col).iter()
+                .any(|line| line.iter().all(|&other| other < height[row][col]))
+        })
+        .count() as u32
+}
+
+pub fn step_2(height: &Grid<u32>) -> u32 {
+    trees(height)
+        .map(|(row, col)| {
+            lines_of_sight(height, row, col).iter()
+                .map(|line| match line.iter().position(|&other| other >= height[row][col]) {
+                    Some(blocking_tree) => blocking_tree as u32 + 1,
+                    None => line.len() as u32,
+                })
+                .product::<u32>()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn solve_str(input: &str) -> Result<(u32, u32), ParseError> {
+    let parsed = crate::parse_input(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
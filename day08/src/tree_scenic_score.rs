@@ -1,33 +1,21 @@
-use std::fs;
+use aoc_common::Grid;
 
 #[derive(Debug)]
 struct TreeGrid {
-    height: Vec<Vec<u32>>,
-    scenic_score: Vec<Vec<u32>>,
+    height: Grid<u32>,
+    scenic_score: Grid<u32>,
 }
 
 impl TreeGrid {
-    fn from_file(filename: &str) -> TreeGrid {
-        let height: Vec<Vec<u32>> = fs::read_to_string(filename).expect("Cannot read the input")
-            .split("\n")
-            .filter(|row| row.len() > 0)
-            .map(|row| row.chars().map(|ch| ch.to_digit(10).unwrap()).collect())
-            .collect();
-
-        let tree_in_row = height[0].len();
-        let mut scenic_score: Vec<Vec<u32>> = Vec::new();
-        for _ in 0..height.len() {
-            let mut row: Vec<u32> = Vec::new();
-            row.resize(tree_in_row, 0);
-            scenic_score.push(row);
-        }
+    fn new(height: &Grid<u32>) -> TreeGrid {
+        let scenic_score = height.with_same_size(0);
 
-        TreeGrid { height, scenic_score }
+        TreeGrid { height: height.clone(), scenic_score }
     }
 
     fn calculate_scenic_score(&mut self) {
-        for row in 1..self.height.len() {
-            for col in 1..self.height[0].len() {
+        for row in 1..self.height.height() {
+            for col in 1..self.height.width() {
                 let mut scenic_score = 1;
 
                 scenic_score *= self.get_visible_tree_at_top(row, col);
@@ -55,7 +43,7 @@ impl TreeGrid {
     }
 
     fn is_visible_from_below(&self, row: usize, col: usize) -> u32 {
-        let num_rows = self.height.len();
+        let num_rows = self.height.height();
         let mut visible_from_below = 0;
 
         let current_tree_height = self.height[row][col];
@@ -84,7 +72,7 @@ impl TreeGrid {
     }
 
     fn is_visible_from_right(&self, row: usize, col: usize) -> u32 {
-        let num_cols = self.height[0].len();
+        let num_cols = self.height.width();
         let mut visible_from_right = 0;
 
         let current_tree_height = self.height[row][col];
@@ -99,7 +87,7 @@ impl TreeGrid {
     }
 
     fn get_max_scenic_score(&self) -> u32 {
-        self.scenic_score.iter()
+        self.scenic_score.rows()
             .map(|row| row.iter().max().unwrap())
             .max()
             .unwrap()
@@ -108,9 +96,31 @@ impl TreeGrid {
 }
 
 
-pub fn step_2(filename: &str) -> u32 {
-    let mut trees = TreeGrid::from_file(filename);
+pub fn step_2(height: &Grid<u32>) -> u32 {
+    let mut trees = TreeGrid::new(height);
     trees.calculate_scenic_score();
 
     trees.get_max_scenic_score()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    #[test]
+    fn counts_the_trees_visible_in_each_direction() {
+        let trees = TreeGrid::new(&crate::read_input(EXAMPLE).unwrap());
+
+        assert_eq!(trees.get_visible_tree_at_top(3, 2), 2);
+        assert_eq!(trees.is_visible_from_left(3, 2), 2);
+        assert_eq!(trees.is_visible_from_below(3, 2), 1);
+        assert_eq!(trees.is_visible_from_right(3, 2), 2);
+    }
+
+    #[test]
+    fn step_2_finds_the_highest_scenic_score() {
+        assert_eq!(step_2(&crate::read_input(EXAMPLE).unwrap()), 8);
+    }
+}
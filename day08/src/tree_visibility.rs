@@ -1,4 +1,4 @@
-use std::fs;
+use aoc_common::Grid;
 
 const VISIBLE_TOP: u32 = 1 << 0;
 const VISIBLE_BELOW: u32 = 1 << 1;
@@ -7,32 +7,20 @@ const VISIBLE_RIGHT: u32 = 1 << 3;
 
 #[derive(Debug)]
 struct TreeGrid {
-    height: Vec<Vec<u32>>,
-    visible: Vec<Vec<u32>>,
+    height: Grid<u32>,
+    visible: Grid<u32>,
 }
 
 impl TreeGrid {
-    fn from_file(filename: &str) -> TreeGrid {
-        let height: Vec<Vec<u32>> = fs::read_to_string(filename).expect("Cannot read the input")
-            .split("\n")
-            .filter(|row| row.len() > 0)
-            .map(|row| row.chars().map(|ch| ch.to_digit(10).unwrap()).collect())
-            .collect();
-
-        let tree_in_row = height[0].len();
-        let mut visible: Vec<Vec<u32>> = Vec::new();
-        for _ in 0..height.len() {
-            let mut row: Vec<u32> = Vec::new();
-            row.resize(tree_in_row, 0);
-            visible.push(row);
-        }
+    fn new(height: &Grid<u32>) -> TreeGrid {
+        let visible = height.with_same_size(0);
 
-        TreeGrid { height, visible }
+        TreeGrid { height: height.clone(), visible }
     }
 
     fn detect_visible_trees(&mut self) {
-        for row in 0..self.height.len() {
-            for col in 0..self.height[0].len() {
+        for row in 0..self.height.height() {
+            for col in 0..self.height.width() {
                 if self.is_visible_from_top(row, col) {
                     self.visible[row][col] |= VISIBLE_TOP;
                 }
@@ -71,7 +59,7 @@ impl TreeGrid {
     }
 
     fn is_visible_from_below(&self, row: usize, col: usize) -> bool {
-        let num_rows = self.height.len();
+        let num_rows = self.height.height();
         if row == num_rows - 1 {
             return true;
         }
@@ -108,7 +96,7 @@ impl TreeGrid {
     }
 
     fn is_visible_from_right(&self, row: usize, col: usize) -> bool {
-        let num_cols = self.height[0].len();
+        let num_cols = self.height.width();
         if col == num_cols - 1 {
             return true;
         }
@@ -127,16 +115,38 @@ impl TreeGrid {
     }
 
     fn get_total_visible_trees(&self) -> u32 {
-        self.visible.iter()
+        self.visible.rows()
             .map(|row| row.iter().filter(|&visible| *visible > 0).count() as u32)
             .sum::<u32>()
     }
 }
 
 
-pub fn step_1(filename: &str) -> u32 {
-    let mut trees = TreeGrid::from_file(filename);
+pub fn step_1(height: &Grid<u32>) -> u32 {
+    let mut trees = TreeGrid::new(height);
     trees.detect_visible_trees();
 
     trees.get_total_visible_trees()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+
+    #[test]
+    fn detects_the_directions_a_tree_is_visible_from() {
+        let mut trees = TreeGrid::new(&crate::read_input(EXAMPLE).unwrap());
+        trees.detect_visible_trees();
+
+        assert_eq!(trees.visible[1][1], VISIBLE_TOP | VISIBLE_LEFT);
+        assert_eq!(trees.visible[1][3], 0);
+        assert_eq!(trees.visible[2][1], VISIBLE_RIGHT);
+    }
+
+    #[test]
+    fn step_1_counts_the_visible_trees() {
+        assert_eq!(step_1(&crate::read_input(EXAMPLE).unwrap()), 21);
+    }
+}
@@ -0,0 +1,26 @@
+use std::fmt::Write;
+
+use clap::Args;
+use rand::Rng;
+
+#[derive(Args, Debug, Clone)]
+pub struct Params {
+    /// Number of head movements
+    #[arg(long, default_value_t = 2000)]
+    pub moves: usize,
+
+    /// Most steps in a single movement
+    #[arg(long, default_value_t = 20)]
+    pub max_step: u32,
+}
+
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, params: &Params) -> String {
+    let mut input = String::new();
+
+    for _ in 0..params.moves {
+        let direction = ['L', 'R', 'U', 'D'][rng.gen_range(0..4)];
+        writeln!(input, "{} {}", direction, rng.gen_range(1..=params.max_step.max(1))).unwrap();
+    }
+
+    input
+}
@@ -0,0 +1,248 @@
+pub mod generator;
+pub mod reference;
+
+use std::{collections::HashSet, hash::Hash};
+
+use aoc_common::{lines, Answer, Error, ParseError, Solution};
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+    Top,
+    Bottom
+}
+
+#[derive(Debug)]
+pub struct Movement {
+    pub direction: Direction,
+    pub step: u32,
+}
+
+pub fn read_input(filename: &str) -> Result<Vec<Movement>, Error> {
+    aoc_common::parse_file(filename, parse_input)
+}
+
+fn parse_movement(line_number: usize, row: &str) -> Result<Movement, ParseError> {
+    let (direction, step) = row.split_once(' ')
+        .ok_or_else(|| ParseError::whole_line(line_number, row, "expected a direction and a number of steps"))?;
+
+    let direction = match direction {
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        "U" => Direction::Top,
+        "D" => Direction::Bottom,
+        _ => return Err(ParseError::in_line(line_number, row, direction, "unknown direction, expected one of L, R, U, D")),
+    };
+
+    let step = step.parse::<u32>()
+        .map_err(|_| ParseError::in_line(line_number, row, step, "expected a number of steps"))?;
+
+    Ok(Movement { direction , step })
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Movement>, ParseError> {
+    lines(input)
+        .map(|(line_number, row)| parse_movement(line_number, row))
+        .collect()
+}
+
+#[derive(Hash, Eq, PartialEq, Clone, Copy)]
+struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+struct KnotsRope {
+    knots: Vec<Point>,
+    trail_history: HashSet<Point>
+}
+
+impl KnotsRope {
+    fn new(num_knots: u32) -> KnotsRope {
+        KnotsRope { knots: (0..num_knots).map(|_| Point { x: 0, y: 0 }).collect(), trail_history: HashSet::new() }
+    }
+
+    fn move_head(&mut self, head_movement: &[Movement]) {
+        for movement in head_movement {
+            match movement.direction {
+                Direction::Left => {
+                    for _ in 0..movement.step {
+                        self.move_head_to_left();
+                        self.trail_history.insert(*self.knots.last().unwrap());
+                    }
+                }
+                Direction::Right => {
+                    for _ in 0..movement.step {
+                        self.move_head_to_right();
+                        self.trail_history.insert(*self.knots.last().unwrap());
+                    }
+                }
+                Direction::Top => {
+                    for _ in 0..movement.step {
+                        self.move_head_to_top();
+                        self.trail_history.insert(*self.knots.last().unwrap());
+                    }
+                }
+                Direction::Bottom => {
+                    for _ in 0..movement.step {
+                        self.move_head_to_bottom();
+                        self.trail_history.insert(*self.knots.last().unwrap());
+                    }
+                }
+            }
+        }
+    }
+
+    fn move_head_to_right(&mut self) {
+        self.knots[0].y += 1;
+
+        for idx in 1..self.knots.len() {
+            let adjusted = KnotsRope::adjust_tail_if_not_adjacent(self.knots[idx - 1], &mut self.knots[idx]);
+            if !adjusted {
+                break;
+            }
+        }
+    }
+
+    fn adjust_tail_if_not_adjacent(head: Point, tail: &mut Point) -> bool {
+        if KnotsRope::are_head_and_tail_adjacent(&head, tail) {
+            return false;
+        }
+
+        let different_row = head.x != tail.x;
+        let different_col = head.y != tail.y;
+
+        let x_direction = if head.x > tail.x { 1 } else { -1 };
+        let y_direction = if head.y > tail.y { 1 } else { -1 };
+
+        if different_row && different_col {
+            tail.x += x_direction;
+            tail.y += y_direction;
+            return true;
+        } else if different_row {
+            tail.x += x_direction;
+            return true;
+        } else if different_col {
+            tail.y += y_direction;
+            return true;
+        }
+
+        false
+    }
+
+    fn move_head_to_left(&mut self) {
+        self.knots[0].y -= 1;
+
+        for idx in 1..self.knots.len() {
+            KnotsRope::adjust_tail_if_not_adjacent(self.knots[idx - 1], &mut self.knots[idx]);
+        }
+    }
+
+    fn move_head_to_top(&mut self) {
+        self.knots[0].x += 1;
+
+        for idx in 1..self.knots.len() {
+            KnotsRope::adjust_tail_if_not_adjacent(self.knots[idx - 1], &mut self.knots[idx]);
+        }
+    }
+
+    fn move_head_to_bottom(&mut self) {
+        self.knots[0].x -= 1;
+
+        for idx in 1..self.knots.len() {
+            KnotsRope::adjust_tail_if_not_adjacent(self.knots[idx - 1], &mut self.knots[idx]);
+        }
+    }
+
+    fn are_head_and_tail_adjacent(head: &Point, tail: &Point) -> bool {
+        let x_dist = head.x.abs_diff(tail.x);
+        let y_dist = head.y.abs_diff(tail.y);
+
+        x_dist <= 1 && y_dist <= 1
+    }
+
+    fn get_position_traversed_by_tail(&self) -> usize {
+        self.trail_history.len()
+    }
+}
+
+pub fn step_1(input: &[Movement]) -> u32 {
+    let mut rope = KnotsRope::new(2);
+    rope.move_head(input);
+
+    rope.get_position_traversed_by_tail() as u32
+}
+
+pub fn step_2(input: &[Movement]) -> u32 {
+    let mut rope = KnotsRope::new(10);
+    rope.move_head(input);
+
+    rope.get_position_traversed_by_tail() as u32
+}
+
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn solve_str(input: &str) -> Result<(u32, u32), ParseError> {
+    let parsed = parse_input(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn default_input(&self) -> &'static str {
+        DEFAULT_INPUT
+    }
+
+    fn step_1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_1(&parse_input(input)?).into())
+    }
+
+    fn step_2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(step_2(&parse_input(input)?).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt");
+    const LARGER_EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example_larger.txt");
+
+    #[test]
+    fn detects_adjacent_knots() {
+        let head = Point { x: 1, y: 1 };
+
+        assert!(KnotsRope::are_head_and_tail_adjacent(&head, &Point { x: 0, y: 0 }));
+        assert!(KnotsRope::are_head_and_tail_adjacent(&head, &Point { x: 1, y: 1 }));
+        assert!(!KnotsRope::are_head_and_tail_adjacent(&head, &Point { x: 3, y: 1 }));
+    }
+
+    #[test]
+    fn moves_the_tail_towards_the_head() {
+        let mut tail = Point { x: 0, y: 0 };
+        assert!(KnotsRope::adjust_tail_if_not_adjacent(Point { x: 2, y: 1 }, &mut tail));
+        assert_eq!((tail.x, tail.y), (1, 1));
+
+        assert!(!KnotsRope::adjust_tail_if_not_adjacent(Point { x: 2, y: 1 }, &mut tail));
+        assert_eq!((tail.x, tail.y), (1, 1));
+    }
+
+    #[test]
+    fn step_1_counts_the_positions_visited_by_the_tail() {
+        assert_eq!(step_1(&read_input(EXAMPLE).unwrap()), 13);
+    }
+
+    #[test]
+    fn step_2_counts_the_positions_visited_by_the_last_knot() {
+        assert_eq!(step_2(&read_input(EXAMPLE).unwrap()), 1);
+        assert_eq!(step_2(&read_input(LARGER_EXAMPLE).unwrap()), 36);
+    }
+}
@@ -0,0 +1,52 @@
+//! After every step of the head the whole rope is updated, even the knots that cannot move.
+
+use std::collections::HashSet;
+
+use aoc_common::ParseError;
+
+use crate::{Direction, Movement};
+
+fn count_tail_positions(movements: &[Movement], num_knots: usize) -> u32 {
+    let mut knots = vec![(0i32, 0i32); num_knots];
+    let mut tail_positions = HashSet::from([(0, 0)]);
+
+    for movement in movements {
+        let (dx, dy) = match movement.direction {
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+            Direction::Top => (0, 1),
+            Direction::Bottom => (0, -1),
+        };
+
+        for _ in 0..movement.step {
+            knots[0].0 += dx;
+            knots[0].1 += dy;
+
+            for idx in 1..num_knots {
+                let (x_dist, y_dist) = (knots[idx - 1].0 - knots[idx].0, knots[idx - 1].1 - knots[idx].1);
+                if x_dist.abs() > 1 || y_dist.abs() > 1 {
+                    knots[idx].0 += x_dist.signum();
+                    knots[idx].1 += y_dist.signum();
+                }
+            }
+
+            tail_positions.insert(knots[num_knots - 1]);
+        }
+    }
+
+    tail_positions.len() as u32
+}
+
+pub fn step_1(movements: &[Movement]) -> u32 {
+    count_tail_positions(movements, 2)
+}
+
+pub fn step_2(movements: &[Movement]) -> u32 {
+    count_tail_positions(movements, 10)
+}
+
+pub fn solve_str(input: &str) -> Result<(u32, u32), ParseError> {
+    let parsed = crate::parse_input(input)?;
+
+    Ok((step_1(&parsed), step_2(&parsed)))
+}